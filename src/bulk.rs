@@ -0,0 +1,414 @@
+//! Utilities for operations that execute many requests at once.
+//!
+//! Bulk operations run their underlying requests concurrently up to a limit.
+//! Requests are still passed through the client's ratelimiter, so requests
+//! sharing a ratelimit bucket are queued by the client rather than being
+//! rejected by Discord.
+//!
+//! Progress can be observed while an operation runs by providing a channel
+//! sender, over which a [`BulkProgress`] event is sent as each item completes.
+//!
+//! Every bulk operation is configured with the same [`BulkOptions`].
+
+use crate::Error;
+use futures_util::{
+    future,
+    stream::{self, StreamExt},
+};
+use std::{
+    future::Future,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+use tokio::sync::mpsc::UnboundedSender;
+use twilight_validate::request::{audit_reason as validate_audit_reason, ValidationError};
+
+/// Default number of requests a bulk operation has in flight at once.
+pub const DEFAULT_CONCURRENCY: usize = 5;
+
+/// Token used to cancel a bulk operation that is in progress.
+///
+/// Cancelling a bulk operation stops it from starting any new requests;
+/// requests that are already in flight are allowed to finish. Items that were
+/// never started are reported in [`BulkOutcome::skipped`].
+///
+/// Tokens are cheap to clone, and all clones refer to the same cancellation
+/// state.
+#[derive(Clone, Debug, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    /// Create a new token that has not been cancelled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancel the operations using this token.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Release);
+    }
+
+    /// Whether the token has been cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Acquire)
+    }
+}
+
+/// Status of an item in a bulk operation.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum BulkStatus {
+    /// Item's request failed.
+    Failed,
+    /// Item's request was never started because the operation was cancelled.
+    Skipped,
+    /// Item's request succeeded.
+    Succeeded,
+}
+
+/// Progress event sent when an item of a bulk operation completes.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub struct BulkProgress<T> {
+    /// Number of items that have completed, including this one.
+    pub completed: usize,
+    /// Item that completed.
+    pub item: T,
+    /// Status of the item.
+    pub status: BulkStatus,
+    /// Total number of items in the operation.
+    pub total: usize,
+}
+
+/// Outcome of a bulk operation, reporting the result of each item.
+///
+/// Items are listed in the order their requests completed.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct BulkOutcome<T> {
+    /// Items whose requests failed, along with the error that occurred.
+    pub failed: Vec<(T, Error)>,
+    /// Items whose requests were never started because the operation was
+    /// cancelled.
+    pub skipped: Vec<T>,
+    /// Items whose requests succeeded.
+    pub succeeded: Vec<T>,
+}
+
+impl<T> BulkOutcome<T> {
+    /// Whether every item's request succeeded.
+    pub fn is_complete(&self) -> bool {
+        self.failed.is_empty() && self.skipped.is_empty()
+    }
+}
+
+impl<T> Default for BulkOutcome<T> {
+    fn default() -> Self {
+        Self {
+            failed: Vec::new(),
+            skipped: Vec::new(),
+            succeeded: Vec::new(),
+        }
+    }
+}
+
+/// Options shared by bulk operations.
+///
+/// Pass options to an operation via its `options` method:
+///
+/// ```rust,no_run
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use std::env;
+/// use twilight_http_resource::{bulk::BulkOptions, Resource};
+/// use twilight_http::Client;
+/// use twilight_model::id::Id;
+///
+/// let client = Client::new(env::var("DISCORD_TOKEN")?);
+/// let user_ids = [Id::new(1), Id::new(2)];
+///
+/// let outcome = client
+///     .guilds()
+///     .bans(Id::new(3))
+///     .post_list(&user_ids)
+///     .options(BulkOptions::new().concurrency(2).reason("raid")?)
+///     .exec()
+///     .await;
+/// # Ok(()) }
+/// ```
+#[derive(Debug)]
+#[must_use = "bulk options must be passed to an operation"]
+pub struct BulkOptions<'a, T> {
+    pub(crate) cancel: Option<CancelToken>,
+    pub(crate) concurrency: usize,
+    pub(crate) progress: Option<UnboundedSender<BulkProgress<T>>>,
+    pub(crate) reason: Option<&'a str>,
+}
+
+impl<'a, T> BulkOptions<'a, T> {
+    /// Create options with the default concurrency and nothing else set.
+    pub const fn new() -> Self {
+        Self {
+            cancel: None,
+            concurrency: DEFAULT_CONCURRENCY,
+            progress: None,
+            reason: None,
+        }
+    }
+
+    /// Set a token that can be used to cancel the operation.
+    pub fn cancel(mut self, token: CancelToken) -> Self {
+        self.cancel = Some(token);
+
+        self
+    }
+
+    /// Set the maximum number of requests in flight at once.
+    ///
+    /// Defaults to [`DEFAULT_CONCURRENCY`]. A value of zero is treated as one.
+    pub const fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency;
+
+        self
+    }
+
+    /// Set a channel to send a progress event to as each item completes.
+    pub fn progress(mut self, sender: UnboundedSender<BulkProgress<T>>) -> Self {
+        self.progress = Some(sender);
+
+        self
+    }
+
+    /// Attach an audit log reason to each request.
+    ///
+    /// Operations that have a reason of their own for an item, such as a ban
+    /// import, only use this reason for items without one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`AuditReason`] if the length is invalid.
+    ///
+    /// [`AuditReason`]: twilight_validate::request::ValidationErrorType::AuditReason
+    pub fn reason(mut self, reason: &'a str) -> Result<Self, ValidationError> {
+        validate_audit_reason(reason)?;
+        self.reason = Some(reason);
+
+        Ok(self)
+    }
+
+    /// Run an operation for each item using these options.
+    pub(crate) async fn run<F, Fut>(&self, items: &[T], f: F) -> BulkOutcome<T>
+    where
+        T: Copy,
+        F: Fn(T) -> Fut,
+        Fut: Future<Output = Result<(), Error>>,
+    {
+        run(
+            items,
+            self.concurrency,
+            self.cancel.as_ref(),
+            self.progress.as_ref(),
+            f,
+        )
+        .await
+    }
+}
+
+impl<T> Default for BulkOptions<'_, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Run an operation for each item with at most `concurrency` operations in
+/// flight at once.
+///
+/// If a progress sender is provided then an event is sent to it as each item
+/// completes. A receiver that has been dropped is ignored.
+pub(crate) async fn run<T, F, Fut>(
+    items: &[T],
+    concurrency: usize,
+    cancel: Option<&CancelToken>,
+    progress: Option<&UnboundedSender<BulkProgress<T>>>,
+    f: F,
+) -> BulkOutcome<T>
+where
+    T: Copy,
+    F: Fn(T) -> Fut,
+    Fut: Future<Output = Result<(), Error>>,
+{
+    let f = &f;
+    let total = items.len();
+    let mut completed = 0;
+
+    stream::iter(items.iter().copied())
+        .map(|item| async move {
+            if cancel.is_some_and(CancelToken::is_cancelled) {
+                return (item, None);
+            }
+
+            (item, Some(f(item).await))
+        })
+        .buffer_unordered(concurrency.max(1))
+        .fold(BulkOutcome::default(), |mut outcome, (item, result)| {
+            let status = match result {
+                Some(Ok(())) => {
+                    outcome.succeeded.push(item);
+
+                    BulkStatus::Succeeded
+                }
+                Some(Err(source)) => {
+                    outcome.failed.push((item, source));
+
+                    BulkStatus::Failed
+                }
+                None => {
+                    outcome.skipped.push(item);
+
+                    BulkStatus::Skipped
+                }
+            };
+
+            completed += 1;
+
+            if let Some(progress) = progress {
+                let _result = progress.send(BulkProgress {
+                    completed,
+                    item,
+                    status,
+                    total,
+                });
+            }
+
+            future::ready(outcome)
+        })
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BulkOptions, BulkOutcome, BulkProgress, BulkStatus, CancelToken};
+    use crate::{Error, ErrorType};
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use tokio::sync::mpsc;
+    use twilight_model::id::{marker::UserMarker, Id};
+
+    assert_impl_all!(BulkOptions<'_, Id<UserMarker>>: Debug, Default, Send, Sync);
+    assert_impl_all!(BulkOutcome<Id<UserMarker>>: Debug, Default, Send, Sync);
+    assert_impl_all!(BulkProgress<Id<UserMarker>>: Clone, Debug, Eq, Send, Sync);
+    assert_impl_all!(BulkStatus: Clone, Copy, Debug, Eq, Send, Sync);
+    assert_impl_all!(CancelToken: Clone, Debug, Default, Send, Sync);
+
+    /// Succeed for every item except 2.
+    async fn fail_two(item: u64) -> Result<(), Error> {
+        if item == 2 {
+            Err(Error {
+                kind: ErrorType::Request,
+                source: None,
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn progress() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+
+        let outcome = super::run(&[1, 2, 3], 1, None, Some(&tx), fail_two).await;
+        drop(tx);
+
+        assert_eq!(vec![1, 3], outcome.succeeded);
+        assert_eq!(
+            vec![2],
+            outcome
+                .failed
+                .iter()
+                .map(|(item, _)| *item)
+                .collect::<Vec<_>>()
+        );
+        assert!(outcome.skipped.is_empty());
+        assert!(!outcome.is_complete());
+
+        let mut events = Vec::new();
+
+        while let Some(event) = rx.recv().await {
+            events.push(event);
+        }
+
+        assert_eq!(
+            vec![
+                BulkProgress {
+                    completed: 1,
+                    item: 1,
+                    status: BulkStatus::Succeeded,
+                    total: 3,
+                },
+                BulkProgress {
+                    completed: 2,
+                    item: 2,
+                    status: BulkStatus::Failed,
+                    total: 3,
+                },
+                BulkProgress {
+                    completed: 3,
+                    item: 3,
+                    status: BulkStatus::Succeeded,
+                    total: 3,
+                },
+            ],
+            events
+        );
+    }
+
+    #[tokio::test]
+    async fn cancel_before_start() {
+        let cancel = CancelToken::new();
+        cancel.cancel();
+
+        let outcome = super::run(&[1, 2, 3], 2, Some(&cancel), None, fail_two).await;
+
+        assert_eq!(vec![1, 2, 3], outcome.skipped);
+        assert!(outcome.failed.is_empty());
+        assert!(outcome.succeeded.is_empty());
+    }
+
+    #[tokio::test]
+    async fn cancel_while_running() {
+        let cancel = CancelToken::new();
+        let (tx, mut rx) = mpsc::unbounded_channel();
+
+        let outcome = super::run(&[1, 2, 3], 1, Some(&cancel), Some(&tx), |item| {
+            // Cancel once the first item is running; later items aren't
+            // started since only one item runs at a time.
+            cancel.cancel();
+
+            fail_two(item)
+        })
+        .await;
+        drop(tx);
+
+        assert_eq!(vec![1], outcome.succeeded);
+        assert_eq!(vec![2, 3], outcome.skipped);
+        assert!(outcome.failed.is_empty());
+        assert!(!outcome.is_complete());
+
+        let mut statuses = Vec::new();
+
+        while let Some(event) = rx.recv().await {
+            statuses.push(event.status);
+        }
+
+        assert_eq!(
+            vec![
+                BulkStatus::Succeeded,
+                BulkStatus::Skipped,
+                BulkStatus::Skipped
+            ],
+            statuses
+        );
+    }
+}
@@ -1,9 +1,9 @@
 //! Work with guilds and their relationships.
 
 pub mod message;
+pub mod permission_overwrite;
 
 mod invite;
-mod permission_overwrite;
 mod pin;
 mod webhook;
 
@@ -0,0 +1,84 @@
+use crate::Error;
+use twilight_http::{
+    client::Client,
+    request::channel::{DeleteChannelPermissionConfigured, UpdateChannelPermission},
+};
+use twilight_model::{
+    channel::permission_overwrite::{
+        PermissionOverwrite as ChannelPermissionOverwrite,
+        PermissionOverwriteType as ChannelPermissionOverwriteType,
+    },
+    guild::Permissions,
+    http::permission_overwrite::{PermissionOverwrite, PermissionOverwriteType},
+    id::{
+        marker::{ChannelMarker, UserMarker},
+        Id,
+    },
+};
+
+/// Work with a channel's permission overwrite for a member.
+#[derive(Clone, Debug)]
+pub struct ChannelPermissionOverwriteMemberResource<'a>(
+    &'a Client,
+    Id<ChannelMarker>,
+    Id<UserMarker>,
+);
+
+impl<'a> ChannelPermissionOverwriteMemberResource<'a> {
+    /// Create a resource instance to work with a channel's permission
+    /// overwrite for a member.
+    pub const fn new(
+        client: &'a Client,
+        channel_id: Id<ChannelMarker>,
+        user_id: Id<UserMarker>,
+    ) -> Self {
+        Self(client, channel_id, user_id)
+    }
+
+    /// Delete the member's permission overwrite.
+    pub const fn delete(&self) -> DeleteChannelPermissionConfigured<'a> {
+        self.0.delete_channel_permission(self.1).member(self.2)
+    }
+
+    /// Get the member's permission overwrite, if there is one.
+    ///
+    /// The permission overwrite is retrieved from the channel.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ErrorType::Request`] if the channel could
+    /// not be retrieved.
+    ///
+    /// [`ErrorType::Request`]: crate::ErrorType::Request
+    pub async fn get(&self) -> Result<Option<ChannelPermissionOverwrite>, Error> {
+        super::find(
+            self.0,
+            self.1,
+            self.2.cast(),
+            ChannelPermissionOverwriteType::Member,
+        )
+        .await
+    }
+
+    /// Put the member's permission overwrite.
+    pub const fn put(&self, allow: Permissions, deny: Permissions) -> UpdateChannelPermission<'a> {
+        self.0.update_channel_permission(
+            self.1,
+            &PermissionOverwrite {
+                allow: Some(allow),
+                deny: Some(deny),
+                id: self.2.cast(),
+                kind: PermissionOverwriteType::Member,
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ChannelPermissionOverwriteMemberResource;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(ChannelPermissionOverwriteMemberResource<'_>: Clone, Debug, Send, Sync);
+}
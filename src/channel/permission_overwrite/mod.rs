@@ -0,0 +1,176 @@
+//! Work with channel permission overwrites and their targets.
+
+mod member;
+mod role;
+
+pub use self::{
+    member::ChannelPermissionOverwriteMemberResource, role::ChannelPermissionOverwriteRoleResource,
+};
+
+use crate::{Error, ErrorType};
+use twilight_http::{client::Client, request::channel::UpdateChannelPermission};
+use twilight_model::{
+    channel::permission_overwrite::{
+        PermissionOverwrite as ChannelPermissionOverwrite,
+        PermissionOverwriteType as ChannelPermissionOverwriteType,
+    },
+    http::permission_overwrite::{PermissionOverwrite, PermissionOverwriteType},
+    id::{
+        marker::{ChannelMarker, GenericMarker, RoleMarker, UserMarker},
+        Id,
+    },
+};
+
+/// Work with a channel's permission overwrites.
+#[derive(Clone, Debug)]
+pub struct ChannelPermissionOverwriteResource<'a>(&'a Client, Id<ChannelMarker>);
+
+impl<'a> ChannelPermissionOverwriteResource<'a> {
+    /// Create a resource instance to work with a channel's permission
+    /// overwrites.
+    pub const fn new(client: &'a Client, channel_id: Id<ChannelMarker>) -> Self {
+        Self(client, channel_id)
+    }
+
+    /// List a channel's permission overwrites.
+    ///
+    /// Permission overwrites are retrieved from the channel.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ErrorType::Request`] if the channel could
+    /// not be retrieved.
+    pub async fn list(&self) -> Result<Vec<ChannelPermissionOverwrite>, Error> {
+        let channel = self.0.channel(self.1).exec().await?.model().await?;
+
+        Ok(channel.permission_overwrites.unwrap_or_default())
+    }
+
+    /// Put a channel permission overwrite.
+    pub const fn put(
+        &self,
+        permission_overwrite: &PermissionOverwrite,
+    ) -> UpdateChannelPermission<'a> {
+        self.0
+            .update_channel_permission(self.1, permission_overwrite)
+    }
+}
+
+/// RPC calls.
+impl ChannelPermissionOverwriteResource<'_> {
+    /// Replace a channel's permission overwrites with those of its parent
+    /// category.
+    ///
+    /// Overwrites on the parent that the channel is missing or that differ on
+    /// the channel are put onto the channel, and overwrites on the channel
+    /// that the parent does not have are deleted. Overwrites that already
+    /// match are left alone.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ErrorType::MissingParent`] if the channel
+    /// does not have a parent category.
+    ///
+    /// Returns an error of type [`ErrorType::Request`] if a channel could not
+    /// be retrieved or an overwrite could not be updated.
+    pub async fn sync_with_parent(&self) -> Result<(), Error> {
+        let channel = self.0.channel(self.1).exec().await?.model().await?;
+
+        let parent_id = channel.parent_id.ok_or(Error {
+            kind: ErrorType::MissingParent { channel_id: self.1 },
+            source: None,
+        })?;
+
+        let parent = self.0.channel(parent_id).exec().await?.model().await?;
+        let current = channel.permission_overwrites.unwrap_or_default();
+        let desired = parent.permission_overwrites.unwrap_or_default();
+
+        for overwrite in &desired {
+            if current.contains(overwrite) {
+                continue;
+            }
+
+            let kind = match overwrite.kind {
+                ChannelPermissionOverwriteType::Member => PermissionOverwriteType::Member,
+                ChannelPermissionOverwriteType::Role => PermissionOverwriteType::Role,
+                _ => continue,
+            };
+
+            let overwrite = PermissionOverwrite {
+                allow: Some(overwrite.allow),
+                deny: Some(overwrite.deny),
+                id: overwrite.id,
+                kind,
+            };
+
+            self.0
+                .update_channel_permission(self.1, &overwrite)
+                .exec()
+                .await?;
+        }
+
+        for overwrite in current {
+            if desired.iter().any(|desired| desired.id == overwrite.id) {
+                continue;
+            }
+
+            let request = self.0.delete_channel_permission(self.1);
+
+            match overwrite.kind {
+                ChannelPermissionOverwriteType::Member => {
+                    request.member(overwrite.id.cast()).exec().await?;
+                }
+                ChannelPermissionOverwriteType::Role => {
+                    request.role(overwrite.id.cast()).exec().await?;
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// 1:M channel permission overwrite relationships.
+impl<'a> ChannelPermissionOverwriteResource<'a> {
+    /// Work with a channel's permission overwrite for a member.
+    pub const fn member(
+        &self,
+        user_id: Id<UserMarker>,
+    ) -> ChannelPermissionOverwriteMemberResource<'a> {
+        ChannelPermissionOverwriteMemberResource::new(self.0, self.1, user_id)
+    }
+
+    /// Work with a channel's permission overwrite for a role.
+    pub const fn role(
+        &self,
+        role_id: Id<RoleMarker>,
+    ) -> ChannelPermissionOverwriteRoleResource<'a> {
+        ChannelPermissionOverwriteRoleResource::new(self.0, self.1, role_id)
+    }
+}
+
+/// Find a channel's permission overwrite for a target.
+async fn find(
+    client: &Client,
+    channel_id: Id<ChannelMarker>,
+    target_id: Id<GenericMarker>,
+    kind: ChannelPermissionOverwriteType,
+) -> Result<Option<ChannelPermissionOverwrite>, Error> {
+    let overwrites = ChannelPermissionOverwriteResource::new(client, channel_id)
+        .list()
+        .await?;
+
+    Ok(overwrites
+        .into_iter()
+        .find(|overwrite| overwrite.id == target_id && overwrite.kind == kind))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ChannelPermissionOverwriteResource;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(ChannelPermissionOverwriteResource<'_>: Clone, Debug, Send, Sync);
+}
@@ -0,0 +1,271 @@
+use std::{
+    error::Error as StdError,
+    fmt::{Display, Formatter, Result as FmtResult},
+    time::Duration,
+};
+use twilight_http::{
+    api_error::ApiError, error::ErrorType as HttpErrorType, response::DeserializeBodyError,
+    Error as HttpError,
+};
+use twilight_model::id::{
+    marker::{ChannelMarker, RoleMarker, WebhookMarker},
+    Id,
+};
+use twilight_validate::{message::MessageValidationError, request::ValidationError};
+
+/// Error that occurred while executing a resource call.
+#[derive(Debug)]
+pub struct Error {
+    pub(crate) kind: ErrorType,
+    pub(crate) source: Option<Box<dyn StdError + Send + Sync>>,
+}
+
+impl Error {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &ErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn StdError + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (ErrorType, Option<Box<dyn StdError + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+
+    pub(crate) fn exporting(source: impl StdError + Send + Sync + 'static) -> Self {
+        Self {
+            kind: ErrorType::Exporting,
+            source: Some(Box::new(source)),
+        }
+    }
+
+    pub(crate) fn reading(source: impl StdError + Send + Sync + 'static) -> Self {
+        Self {
+            kind: ErrorType::Reading,
+            source: Some(Box::new(source)),
+        }
+    }
+
+    pub(crate) fn request(source: impl StdError + Send + Sync + 'static) -> Self {
+        Self {
+            kind: ErrorType::Request,
+            source: Some(Box::new(source)),
+        }
+    }
+
+    pub(crate) fn validation(source: impl StdError + Send + Sync + 'static) -> Self {
+        Self {
+            kind: ErrorType::Validation,
+            source: Some(Box::new(source)),
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            ErrorType::Deserializing => f.write_str("response body could not be deserialized"),
+            ErrorType::DuplicateName { name } => {
+                f.write_str("name ")?;
+                Display::fmt(name, f)?;
+
+                f.write_str(" was declared more than once")
+            }
+            ErrorType::Exporting => f.write_str("models could not be exported"),
+            ErrorType::ImageUnavailable { path } => {
+                f.write_str("image ")?;
+                Display::fmt(path, f)?;
+
+                f.write_str(" must be provided since the `cdn` feature is disabled")
+            }
+            ErrorType::MissingParent { channel_id } => {
+                f.write_str("channel ")?;
+                Display::fmt(channel_id, f)?;
+
+                f.write_str(" does not have a parent category")
+            }
+            ErrorType::PruneThresholdExceeded { count, threshold } => {
+                f.write_str("prune would remove ")?;
+                Display::fmt(count, f)?;
+                f.write_str(" members, more than the threshold of ")?;
+
+                Display::fmt(threshold, f)
+            }
+            ErrorType::Reading => f.write_str("files could not be read"),
+            ErrorType::Request => f.write_str("request could not be executed"),
+            ErrorType::RoleHierarchy { role_id } => {
+                f.write_str("role ")?;
+                Display::fmt(role_id, f)?;
+
+                f.write_str(" is not below the current user's highest role")
+            }
+            ErrorType::UnknownName { name } => {
+                f.write_str("name ")?;
+                Display::fmt(name, f)?;
+
+                f.write_str(" was not declared")
+            }
+            ErrorType::UnknownRole { role_id } => {
+                f.write_str("role ")?;
+                Display::fmt(role_id, f)?;
+
+                f.write_str(" does not exist in the guild")
+            }
+            ErrorType::UnknownWebhook { webhook_id } => {
+                f.write_str("webhook ")?;
+                Display::fmt(webhook_id, f)?;
+
+                f.write_str(" does not exist in the guild")
+            }
+            ErrorType::UnnamedWebhook { webhook_id } => {
+                f.write_str("webhook ")?;
+                Display::fmt(webhook_id, f)?;
+
+                f.write_str(" does not have a name")
+            }
+            ErrorType::Validation => f.write_str("request fields failed validation"),
+        }
+    }
+}
+
+impl StdError for Error {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn StdError + 'static))
+    }
+}
+
+impl From<DeserializeBodyError> for Error {
+    fn from(source: DeserializeBodyError) -> Self {
+        Self {
+            kind: ErrorType::Deserializing,
+            source: Some(Box::new(source)),
+        }
+    }
+}
+
+impl From<HttpError> for Error {
+    fn from(source: HttpError) -> Self {
+        Self {
+            kind: ErrorType::Request,
+            source: Some(Box::new(source)),
+        }
+    }
+}
+
+impl From<MessageValidationError> for Error {
+    fn from(source: MessageValidationError) -> Self {
+        Self {
+            kind: ErrorType::Validation,
+            source: Some(Box::new(source)),
+        }
+    }
+}
+
+impl From<ValidationError> for Error {
+    fn from(source: ValidationError) -> Self {
+        Self {
+            kind: ErrorType::Validation,
+            source: Some(Box::new(source)),
+        }
+    }
+}
+
+/// Whether an HTTP error is due to the requested entity not existing.
+pub(crate) const fn is_not_found(error: &HttpError) -> bool {
+    matches!(error.kind(), HttpErrorType::Response { status, .. } if status.get() == 404)
+}
+
+/// How long to wait before retrying a request if an HTTP error is due to a
+/// ratelimit.
+pub(crate) fn retry_after(error: &HttpError) -> Option<Duration> {
+    match error.kind() {
+        HttpErrorType::Response {
+            error: ApiError::Ratelimited(ratelimited),
+            ..
+        } => Some(Duration::from_secs_f64(ratelimited.retry_after.max(0.0))),
+        _ => None,
+    }
+}
+
+/// Type of [`Error`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ErrorType {
+    /// Response body could not be deserialized into its model.
+    Deserializing,
+    /// Name was declared more than once.
+    DuplicateName {
+        /// Duplicated name.
+        name: String,
+    },
+    /// Models could not be serialized or written to an export destination.
+    Exporting,
+    /// Image has to be downloaded from Discord's CDN, but the `cdn` feature
+    /// is disabled.
+    ImageUnavailable {
+        /// Path of the image on the CDN.
+        path: String,
+    },
+    /// Channel does not have a parent category.
+    MissingParent {
+        /// ID of the channel.
+        channel_id: Id<ChannelMarker>,
+    },
+    /// Prune would remove more members than allowed.
+    PruneThresholdExceeded {
+        /// Number of members that would be removed.
+        count: u64,
+        /// Maximum number of members allowed to be removed.
+        threshold: u64,
+    },
+    /// Files could not be read from the local filesystem.
+    Reading,
+    /// Request could not be built, sent, or returned an unsuccessful response.
+    Request,
+    /// Role is not, or would not be, below the current user's highest role.
+    RoleHierarchy {
+        /// ID of the role.
+        role_id: Id<RoleMarker>,
+    },
+    /// Name was referenced without being declared.
+    UnknownName {
+        /// Referenced name.
+        name: String,
+    },
+    /// Role does not exist in the guild.
+    UnknownRole {
+        /// ID of the role.
+        role_id: Id<RoleMarker>,
+    },
+    /// Webhook does not exist in the guild.
+    UnknownWebhook {
+        /// ID of the webhook.
+        webhook_id: Id<WebhookMarker>,
+    },
+    /// Webhook does not have a name, so it can't be recreated.
+    UnnamedWebhook {
+        /// ID of the webhook.
+        webhook_id: Id<WebhookMarker>,
+    },
+    /// Request fields failed validation before the request was sent.
+    Validation,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Error, ErrorType};
+    use static_assertions::assert_impl_all;
+    use std::{error::Error as StdError, fmt::Debug};
+
+    assert_impl_all!(ErrorType: Debug, Send, Sync);
+    assert_impl_all!(Error: Debug, Send, StdError, Sync);
+}
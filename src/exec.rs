@@ -0,0 +1,264 @@
+use super::Error;
+use futures_util::Stream;
+use std::{future::Future, pin::Pin};
+use twilight_http::request::{
+    channel::{
+        invite::{CreateInvite, GetChannelInvites, GetInvite},
+        message::{CreateMessage, CrosspostMessage, GetChannelMessages, GetMessage, UpdateMessage},
+        reaction::GetReactions,
+        webhook::{
+            CreateWebhook, ExecuteWebhookAndWait, GetChannelWebhooks, GetWebhook,
+            GetWebhookMessage, UpdateWebhook, UpdateWebhookWithToken,
+        },
+        DeleteChannel, FollowNewsChannel, GetChannel, GetPins, UpdateChannel,
+    },
+    guild::{
+        ban::{GetBan, GetBans},
+        create_guild::CreateGuild,
+        emoji::{CreateEmoji, GetEmoji, GetEmojis, UpdateEmoji},
+        integration::GetGuildIntegrations,
+        member::{
+            AddGuildMember, GetGuildMembers, GetMember, SearchGuildMembers, UpdateGuildMember,
+        },
+        role::{CreateRole, GetGuildRoles, UpdateRole, UpdateRolePositions},
+        CreateGuildChannel, CreateGuildPrune, GetAuditLog, GetGuild, GetGuildChannels,
+        GetGuildInvites, GetGuildPreview, GetGuildPruneCount, GetGuildVanityUrl,
+        GetGuildVoiceRegions, GetGuildWebhooks, GetGuildWelcomeScreen, GetGuildWidget, UpdateGuild,
+        UpdateGuildWelcomeScreen, UpdateGuildWidget,
+    },
+    template::{
+        CreateGuildFromTemplate, CreateTemplate, GetTemplate, GetTemplates, SyncTemplate,
+        UpdateTemplate,
+    },
+    user::{
+        CreatePrivateChannel, GetCurrentUser, GetCurrentUserConnections, GetCurrentUserGuildMember,
+        GetCurrentUserGuilds, GetUser, UpdateCurrentUser,
+    },
+    GetGateway, GetVoiceRegions,
+};
+use twilight_model::{
+    channel::{Channel, FollowedChannel, Message, Webhook},
+    gateway::connection_info::ConnectionInfo,
+    guild::{
+        audit_log::AuditLog, Ban, Emoji, Guild, GuildIntegration, GuildPreview, GuildPrune,
+        GuildWidget, Member, PartialGuild, PartialMember, Role, VanityUrl,
+    },
+    invite::{Invite, WelcomeScreen},
+    template::Template,
+    user::{Connection, CurrentUser, CurrentUserGuild, User},
+    voice::VoiceRegion,
+};
+
+/// Future resolving to a deserialized model or an [`Error`].
+pub type ExecFuture<T> = Pin<Box<dyn Future<Output = Result<T, Error>> + Send>>;
+
+/// Stream of deserialized models, yielding an [`Error`] if a request fails.
+pub type ExecStream<'a, T> = Pin<Box<dyn Stream<Item = Result<T, Error>> + Send + 'a>>;
+
+/// Execute a request and deserialize its response body in one call.
+///
+/// This is implemented for the builders returned by resources whose responses
+/// contain a single model. Instead of writing
+/// `.exec().await?.model().await?` a call site can simply write
+/// `.model().await?`.
+///
+/// # Examples
+///
+/// Get a channel's model:
+///
+/// ```rust,no_run
+/// use std::env;
+/// use twilight_http_resource::{ExecModel, Resource};
+/// use twilight_http::Client;
+/// use twilight_model::id::Id;
+///
+/// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new(env::var("DISCORD_TOKEN")?);
+///
+/// let channel = client.channels().get(Id::new(1)).model().await?;
+/// # Ok(()) }
+/// ```
+pub trait ExecModel {
+    /// Type of model that the response body deserializes into.
+    type Model;
+
+    /// Execute the request and deserialize the response body into its model.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ErrorType::Request`] if the request failed.
+    ///
+    /// Returns an error of type [`ErrorType::Deserializing`] if the response
+    /// body could not be deserialized.
+    ///
+    /// [`ErrorType::Deserializing`]: crate::ErrorType::Deserializing
+    /// [`ErrorType::Request`]: crate::ErrorType::Request
+    fn model(self) -> ExecFuture<Self::Model>;
+}
+
+/// Execute a request and deserialize its response body into a list of models
+/// in one call.
+///
+/// This is implemented for the builders returned by resources' list
+/// endpoints.
+///
+/// # Examples
+///
+/// List a guild's roles:
+///
+/// ```rust,no_run
+/// use std::env;
+/// use twilight_http_resource::{ExecModels, Resource};
+/// use twilight_http::Client;
+/// use twilight_model::id::Id;
+///
+/// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new(env::var("DISCORD_TOKEN")?);
+///
+/// let roles = client.guilds().roles(Id::new(1)).list().models().await?;
+/// # Ok(()) }
+/// ```
+pub trait ExecModels {
+    /// Type of model that each item of the response body deserializes into.
+    type Model;
+
+    /// Execute the request and deserialize the response body into a list of
+    /// models.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ErrorType::Request`] if the request failed.
+    ///
+    /// Returns an error of type [`ErrorType::Deserializing`] if the response
+    /// body could not be deserialized.
+    ///
+    /// [`ErrorType::Deserializing`]: crate::ErrorType::Deserializing
+    /// [`ErrorType::Request`]: crate::ErrorType::Request
+    fn models(self) -> ExecFuture<Vec<Self::Model>>;
+}
+
+/// Implement [`ExecModel`] for requests returning a single model.
+macro_rules! exec_model {
+    ($($request:ident => $model:ty,)*) => {
+        $(
+            impl ExecModel for $request<'_> {
+                type Model = $model;
+
+                fn model(self) -> ExecFuture<Self::Model> {
+                    let future = self.exec();
+
+                    Box::pin(async move { Ok(future.await?.model().await?) })
+                }
+            }
+        )*
+    };
+}
+
+/// Implement [`ExecModels`] for requests returning a list of models.
+macro_rules! exec_models {
+    ($($request:ident => $model:ty,)*) => {
+        $(
+            impl ExecModels for $request<'_> {
+                type Model = $model;
+
+                fn models(self) -> ExecFuture<Vec<Self::Model>> {
+                    let future = self.exec();
+
+                    Box::pin(async move { Ok(future.await?.models().await?) })
+                }
+            }
+        )*
+    };
+}
+
+exec_model! {
+    AddGuildMember => PartialMember,
+    CreateEmoji => Emoji,
+    CreateGuild => PartialGuild,
+    CreateGuildChannel => Channel,
+    CreateGuildFromTemplate => Guild,
+    CreateGuildPrune => GuildPrune,
+    CreateInvite => Invite,
+    CreateMessage => Message,
+    CreatePrivateChannel => Channel,
+    CreateRole => Role,
+    CreateTemplate => Template,
+    CreateWebhook => Webhook,
+    CrosspostMessage => Message,
+    DeleteChannel => Channel,
+    ExecuteWebhookAndWait => Message,
+    FollowNewsChannel => FollowedChannel,
+    GetAuditLog => AuditLog,
+    GetBan => Ban,
+    GetChannel => Channel,
+    GetCurrentUser => CurrentUser,
+    GetCurrentUserGuildMember => Member,
+    GetEmoji => Emoji,
+    GetGateway => ConnectionInfo,
+    GetGuild => Guild,
+    GetGuildPreview => GuildPreview,
+    GetGuildPruneCount => GuildPrune,
+    GetGuildVanityUrl => VanityUrl,
+    GetGuildWelcomeScreen => WelcomeScreen,
+    GetGuildWidget => GuildWidget,
+    GetInvite => Invite,
+    GetMember => Member,
+    GetMessage => Message,
+    GetTemplate => Template,
+    GetUser => User,
+    GetWebhook => Webhook,
+    GetWebhookMessage => Message,
+    SyncTemplate => Template,
+    UpdateChannel => Channel,
+    UpdateCurrentUser => User,
+    UpdateEmoji => Emoji,
+    UpdateGuild => PartialGuild,
+    UpdateGuildMember => Member,
+    UpdateGuildWelcomeScreen => WelcomeScreen,
+    UpdateGuildWidget => GuildWidget,
+    UpdateMessage => Message,
+    UpdateRole => Role,
+    UpdateTemplate => Template,
+    UpdateWebhook => Webhook,
+    UpdateWebhookWithToken => Webhook,
+}
+
+exec_models! {
+    GetBans => Ban,
+    GetChannelInvites => Invite,
+    GetChannelMessages => Message,
+    GetChannelWebhooks => Webhook,
+    GetCurrentUserConnections => Connection,
+    GetCurrentUserGuilds => CurrentUserGuild,
+    GetEmojis => Emoji,
+    GetGuildChannels => Channel,
+    GetGuildIntegrations => GuildIntegration,
+    GetGuildInvites => Invite,
+    GetGuildMembers => Member,
+    GetGuildRoles => Role,
+    GetGuildVoiceRegions => VoiceRegion,
+    GetGuildWebhooks => Webhook,
+    GetPins => Message,
+    GetReactions => User,
+    GetTemplates => Template,
+    GetVoiceRegions => VoiceRegion,
+    SearchGuildMembers => Member,
+    UpdateRolePositions => Role,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ExecModel, ExecModels};
+    use static_assertions::{assert_impl_all, assert_obj_safe};
+    use twilight_http::request::{
+        channel::GetChannel,
+        guild::{member::GetGuildMembers, role::GetGuildRoles, GetGuildWidget, UpdateGuildWidget},
+    };
+
+    assert_impl_all!(GetChannel<'_>: ExecModel);
+    assert_impl_all!(GetGuildWidget<'_>: ExecModel);
+    assert_impl_all!(UpdateGuildWidget<'_>: ExecModel);
+    assert_impl_all!(GetGuildMembers<'_>: ExecModels);
+    assert_impl_all!(GetGuildRoles<'_>: ExecModels);
+    assert_obj_safe!(ExecModel<Model = ()>, ExecModels<Model = ()>);
+}
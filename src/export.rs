@@ -0,0 +1,108 @@
+//! Formats that lists of models can be exported in.
+
+use crate::{Error, ExecStream};
+use futures_util::StreamExt;
+use serde::Serialize;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+/// Format to export a list of models in.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum ExportFormat {
+    /// Comma-separated values, starting with a header record.
+    ///
+    /// Nested values are flattened into a fixed set of columns.
+    Csv,
+    /// One JSON object per line.
+    ///
+    /// Each line contains the entire model.
+    JsonLines,
+}
+
+/// Write every model of a stream to a writer, returning the number of models
+/// exported.
+///
+/// CSV exports start with the `header` record, followed by the fields of each
+/// model as returned by `record`.
+pub(crate) async fn export<T: Serialize>(
+    mut models: ExecStream<'_, T>,
+    mut writer: impl AsyncWrite + Unpin,
+    format: ExportFormat,
+    header: &[&str],
+    record: impl Fn(&T) -> Vec<String>,
+) -> Result<usize, Error> {
+    let mut count = 0;
+
+    if format == ExportFormat::Csv {
+        writer
+            .write_all(csv_record(header).as_bytes())
+            .await
+            .map_err(Error::exporting)?;
+    }
+
+    while let Some(model) = models.next().await {
+        let model = model?;
+
+        let line = match format {
+            ExportFormat::Csv => csv_record(&record(&model)).into_bytes(),
+            ExportFormat::JsonLines => json_line(&model)?,
+        };
+
+        writer.write_all(&line).await.map_err(Error::exporting)?;
+        count += 1;
+    }
+
+    writer.flush().await.map_err(Error::exporting)?;
+
+    Ok(count)
+}
+
+/// Format a record of CSV fields, quoting fields where necessary.
+fn csv_record(fields: &[impl AsRef<str>]) -> String {
+    let mut record = String::new();
+
+    for (idx, field) in fields.iter().enumerate() {
+        let field = field.as_ref();
+
+        if idx > 0 {
+            record.push(',');
+        }
+
+        if field.contains(&[',', '"', '\n', '\r'][..]) {
+            record.push('"');
+            record.push_str(&field.replace('"', "\"\""));
+            record.push('"');
+        } else {
+            record.push_str(field);
+        }
+    }
+
+    record.push('\n');
+
+    record
+}
+
+/// Format a value as a line of JSON.
+fn json_line(value: &impl Serialize) -> Result<Vec<u8>, Error> {
+    let mut line = serde_json::to_vec(value).map_err(Error::exporting)?;
+    line.push(b'\n');
+
+    Ok(line)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{csv_record, ExportFormat};
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_impl_all!(ExportFormat: Clone, Copy, Debug, Eq, Hash, PartialEq, Send, Sync);
+
+    #[test]
+    fn csv_record_quoting() {
+        assert_eq!(
+            "plain,\"with,comma\",\"with \"\"quote\"\"\"\n",
+            csv_record(&["plain", "with,comma", "with \"quote\""])
+        );
+    }
+}
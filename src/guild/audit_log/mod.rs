@@ -1,3 +1,12 @@
+mod poll;
+mod query;
+
+pub use self::{
+    poll::GuildAuditLogPoller,
+    query::{AuditLogEntryContext, GuildAuditLogQuery},
+};
+
+use std::time::Duration;
 use twilight_http::{client::Client, request::guild::GetAuditLog};
 use twilight_model::id::{marker::GuildMarker, Id};
 
@@ -15,6 +24,17 @@ impl<'a> GuildAuditLogResource<'a> {
     pub const fn list(&self) -> GetAuditLog<'a> {
         self.0.audit_log(self.1)
     }
+
+    /// Poll a guild's audit log for new entries on an interval.
+    pub const fn poll(&self, interval: Duration) -> GuildAuditLogPoller<'a> {
+        GuildAuditLogPoller::new(self.0, self.1, interval)
+    }
+
+    /// Query a guild's audit log entries by action type, actor, target, and
+    /// date range.
+    pub const fn query(&self) -> GuildAuditLogQuery<'a> {
+        GuildAuditLogQuery::new(self.0, self.1)
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,165 @@
+use super::query::{self, AuditLogEntryContext, AUDIT_LOG_PAGE_LIMIT};
+use crate::{error, Error, ExecStream};
+use futures_util::stream::{self, StreamExt};
+use std::time::Duration;
+use tokio::time;
+use twilight_http::{client::Client, Error as HttpError};
+use twilight_model::id::{
+    marker::{AuditLogEntryMarker, GuildMarker},
+    Id,
+};
+
+/// Longest delay between polls after repeated errors.
+const MAX_BACKOFF: Duration = Duration::from_secs(300);
+
+/// Poll a guild's audit log for new entries.
+///
+/// The ID of the newest entry is remembered and only entries newer than it
+/// are emitted, oldest first. Unless polling starts after a known entry via
+/// [`after`], entries that exist when polling starts are not emitted.
+///
+/// Errors are emitted on the stream without ending it; the delay before the
+/// next poll doubles after each consecutive error, up to five minutes, or
+/// follows the ratelimit if one was hit.
+///
+/// Created via [`GuildAuditLogResource::poll`].
+///
+/// [`GuildAuditLogResource::poll`]: super::GuildAuditLogResource::poll
+/// [`after`]: Self::after
+#[derive(Debug)]
+#[must_use = "audit log pollers must be configured and streamed"]
+pub struct GuildAuditLogPoller<'a> {
+    after: Option<Id<AuditLogEntryMarker>>,
+    client: &'a Client,
+    guild_id: Id<GuildMarker>,
+    interval: Duration,
+}
+
+/// State carried between polls.
+struct PollState {
+    delay: Duration,
+    newest: Option<Id<AuditLogEntryMarker>>,
+    primed: bool,
+}
+
+impl<'a> GuildAuditLogPoller<'a> {
+    pub(super) const fn new(
+        client: &'a Client,
+        guild_id: Id<GuildMarker>,
+        interval: Duration,
+    ) -> Self {
+        Self {
+            after: None,
+            client,
+            guild_id,
+            interval,
+        }
+    }
+
+    /// Emit entries newer than a known entry, such as the last entry handled
+    /// before a restart.
+    pub const fn after(mut self, entry_id: Id<AuditLogEntryMarker>) -> Self {
+        self.after = Some(entry_id);
+
+        self
+    }
+
+    /// Stream new entries as they are found.
+    pub fn stream(self) -> ExecStream<'a, AuditLogEntryContext> {
+        let Self {
+            after,
+            client,
+            guild_id,
+            interval,
+        } = self;
+
+        let state = PollState {
+            delay: Duration::ZERO,
+            newest: after,
+            primed: after.is_some(),
+        };
+
+        let polls = stream::unfold(state, move |mut state| async move {
+            time::sleep(state.delay).await;
+
+            match fetch(client, guild_id, state.newest).await {
+                Ok(entries) => {
+                    if let Some(newest) = entries.last() {
+                        state.newest = Some(newest.entry.id);
+                    }
+
+                    let entries = if state.primed { entries } else { Vec::new() };
+
+                    state.delay = interval;
+                    state.primed = true;
+
+                    Some((entries.into_iter().map(Ok).collect::<Vec<_>>(), state))
+                }
+                Err(source) => {
+                    let retry_after = source
+                        .source
+                        .as_deref()
+                        .and_then(|source| source.downcast_ref::<HttpError>())
+                        .and_then(error::retry_after);
+
+                    state.delay = retry_after
+                        .unwrap_or_else(|| (state.delay.max(interval) * 2).min(MAX_BACKOFF));
+
+                    Some((vec![Err(source)], state))
+                }
+            }
+        });
+
+        Box::pin(polls.flat_map(stream::iter))
+    }
+}
+
+/// Retrieve entries newer than an entry, oldest first.
+///
+/// If there is no known newest entry then only the first page is retrieved.
+async fn fetch(
+    client: &Client,
+    guild_id: Id<GuildMarker>,
+    newest: Option<Id<AuditLogEntryMarker>>,
+) -> Result<Vec<AuditLogEntryContext>, Error> {
+    let mut entries = Vec::new();
+    let mut before = None;
+
+    loop {
+        let mut request = client.audit_log(guild_id).limit(AUDIT_LOG_PAGE_LIMIT)?;
+
+        if let Some(before) = before {
+            request = request.before(before);
+        }
+
+        let log = request.exec().await?.model().await?;
+        let full = log.entries.len() >= usize::from(AUDIT_LOG_PAGE_LIMIT);
+        let oldest = log.entries.iter().map(|entry| entry.id).min();
+
+        entries.extend(
+            query::join(log)
+                .into_iter()
+                .filter(|context| newest.map_or(true, |newest| context.entry.id > newest)),
+        );
+
+        match oldest {
+            Some(oldest) if full && newest.is_some_and(|newest| oldest > newest) => {
+                before = Some(oldest.get());
+            }
+            _ => break,
+        }
+    }
+
+    entries.sort_by_key(|context| context.entry.id);
+
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GuildAuditLogPoller;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(GuildAuditLogPoller<'_>: Debug, Send, Sync);
+}
@@ -0,0 +1,270 @@
+use crate::{timestamp, Error, ExecStream};
+use futures_util::stream::{self, TryStreamExt};
+use std::{collections::HashMap, time::SystemTime};
+use twilight_http::client::Client;
+use twilight_model::{
+    channel::Webhook,
+    guild::audit_log::{AuditLog, AuditLogEntry, AuditLogEventType, AuditLogGuildIntegration},
+    id::{
+        marker::{GenericMarker, GuildMarker, UserMarker},
+        Id,
+    },
+    user::User,
+};
+
+/// Maximum number of audit log entries Discord returns in a page.
+pub(super) const AUDIT_LOG_PAGE_LIMIT: u16 = 100;
+
+/// Audit log entry along with the users, webhook, and integration it refers
+/// to.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct AuditLogEntryContext {
+    /// Audit log entry.
+    pub entry: AuditLogEntry,
+    /// Integration targeted by the entry.
+    pub integration: Option<AuditLogGuildIntegration>,
+    /// User targeted by the entry.
+    pub target_user: Option<User>,
+    /// User that made the changes.
+    pub user: Option<User>,
+    /// Webhook targeted by the entry.
+    pub webhook: Option<Webhook>,
+}
+
+/// Query a guild's audit log, paging backwards from the newest matching
+/// entry.
+///
+/// Created via [`GuildAuditLogResource::query`].
+///
+/// [`GuildAuditLogResource::query`]: super::GuildAuditLogResource::query
+#[derive(Debug)]
+#[must_use = "audit log queries must be configured and streamed"]
+pub struct GuildAuditLogQuery<'a> {
+    action_type: Option<AuditLogEventType>,
+    actor_id: Option<Id<UserMarker>>,
+    after: Option<SystemTime>,
+    before: Option<SystemTime>,
+    client: &'a Client,
+    guild_id: Id<GuildMarker>,
+    target_id: Option<Id<GenericMarker>>,
+}
+
+impl<'a> GuildAuditLogQuery<'a> {
+    pub(super) const fn new(client: &'a Client, guild_id: Id<GuildMarker>) -> Self {
+        Self {
+            action_type: None,
+            actor_id: None,
+            after: None,
+            before: None,
+            client,
+            guild_id,
+            target_id: None,
+        }
+    }
+
+    /// Filter entries by the type of action that was performed.
+    pub const fn action_type(mut self, action_type: AuditLogEventType) -> Self {
+        self.action_type = Some(action_type);
+
+        self
+    }
+
+    /// Filter entries by the user that made the changes.
+    pub const fn actor(mut self, user_id: Id<UserMarker>) -> Self {
+        self.actor_id = Some(user_id);
+
+        self
+    }
+
+    /// Only include entries created at or after a point in time.
+    pub const fn after(mut self, after: SystemTime) -> Self {
+        self.after = Some(after);
+
+        self
+    }
+
+    /// Only include entries created before a point in time.
+    pub const fn before(mut self, before: SystemTime) -> Self {
+        self.before = Some(before);
+
+        self
+    }
+
+    /// Filter entries by the ID of the entity that was affected.
+    ///
+    /// Discord doesn't support filtering by target, so entries are filtered
+    /// as pages are retrieved.
+    pub const fn target(mut self, target_id: Id<GenericMarker>) -> Self {
+        self.target_id = Some(target_id);
+
+        self
+    }
+
+    /// Stream matching entries from newest to oldest, retrieving pages as
+    /// needed.
+    pub fn stream(self) -> ExecStream<'a, AuditLogEntryContext> {
+        let Self {
+            action_type,
+            actor_id,
+            after,
+            before,
+            client,
+            guild_id,
+            target_id,
+        } = self;
+
+        let after = after.map_or(0, timestamp::snowflake);
+        let before = before.map(timestamp::snowflake);
+
+        let pages = stream::try_unfold(Some(before), move |cursor| async move {
+            let Some(cursor) = cursor else {
+                return Ok::<_, Error>(None);
+            };
+
+            let mut request = client.audit_log(guild_id).limit(AUDIT_LOG_PAGE_LIMIT)?;
+
+            if let Some(action_type) = action_type {
+                request = request.action_type(action_type);
+            }
+
+            if let Some(user_id) = actor_id {
+                request = request.user_id(user_id);
+            }
+
+            if let Some(before) = cursor {
+                request = request.before(before);
+            }
+
+            let log = request.exec().await?.model().await?;
+
+            let next = match log.entries.last() {
+                Some(last)
+                    if log.entries.len() >= usize::from(AUDIT_LOG_PAGE_LIMIT)
+                        && last.id.get() > after =>
+                {
+                    Some(Some(last.id.get()))
+                }
+                _ => None,
+            };
+
+            let entries = join(log).into_iter().filter(move |context| {
+                context.entry.id.get() >= after
+                    && (target_id.is_none() || context.entry.target_id == target_id)
+            });
+
+            Ok(Some((stream::iter(entries.map(Ok)), next)))
+        });
+
+        Box::pin(pages.try_flatten())
+    }
+}
+
+/// Join the users, webhooks, and integrations included in an audit log onto
+/// its entries.
+pub(super) fn join(log: AuditLog) -> Vec<AuditLogEntryContext> {
+    let AuditLog {
+        entries,
+        integrations,
+        users,
+        webhooks,
+        ..
+    } = log;
+
+    let users = users
+        .into_iter()
+        .map(|user| (user.id, user))
+        .collect::<HashMap<_, _>>();
+    let webhooks = webhooks
+        .into_iter()
+        .map(|webhook| (webhook.id, webhook))
+        .collect::<HashMap<_, _>>();
+
+    entries
+        .into_iter()
+        .map(|entry| {
+            let target_id = entry.target_id;
+
+            AuditLogEntryContext {
+                integration: target_id.and_then(|target_id| {
+                    integrations
+                        .iter()
+                        .find(|integration| integration.id == Some(target_id.cast()))
+                        .cloned()
+                }),
+                target_user: target_id.and_then(|target_id| users.get(&target_id.cast()).cloned()),
+                user: entry
+                    .user_id
+                    .and_then(|user_id| users.get(&user_id).cloned()),
+                webhook: target_id.and_then(|target_id| webhooks.get(&target_id.cast()).cloned()),
+                entry,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{join, AuditLogEntryContext, GuildAuditLogQuery};
+    use serde_json::json;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_model::{guild::audit_log::AuditLog, id::Id};
+
+    assert_impl_all!(AuditLogEntryContext: Clone, Debug, Eq, Send, Sync);
+    assert_impl_all!(GuildAuditLogQuery<'_>: Debug, Send, Sync);
+
+    fn user(id: &str) -> serde_json::Value {
+        json!({
+            "avatar": null,
+            "discriminator": "0001",
+            "id": id,
+            "username": "user",
+        })
+    }
+
+    #[test]
+    fn joins() -> Result<(), serde_json::Error> {
+        let log = serde_json::from_value::<AuditLog>(json!({
+            "audit_log_entries": [
+                {
+                    "action_type": 22,
+                    "id": "30",
+                    "target_id": "2",
+                    "user_id": "1",
+                },
+                {
+                    "action_type": 50,
+                    "id": "20",
+                    "target_id": "3",
+                    "user_id": "1",
+                },
+            ],
+            "guild_scheduled_events": [],
+            "integrations": [],
+            "threads": [],
+            "users": [user("1"), user("2")],
+            "webhooks": [],
+        }))?;
+
+        let joined = join(log);
+
+        assert_eq!(2, joined.len());
+        assert_eq!(
+            Some(Id::new(1)),
+            joined[0].user.as_ref().map(|user| user.id)
+        );
+        assert_eq!(
+            Some(Id::new(2)),
+            joined[0].target_user.as_ref().map(|user| user.id)
+        );
+        assert_eq!(
+            Some(Id::new(1)),
+            joined[1].user.as_ref().map(|user| user.id)
+        );
+        assert!(joined[1].target_user.is_none());
+        assert!(joined[1].webhook.is_none());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,171 @@
+use crate::{
+    bulk::{BulkOptions, BulkOutcome},
+    Error,
+};
+use twilight_http::{client::Client, request::AuditLogReason};
+use twilight_model::id::{
+    marker::{GuildMarker, UserMarker},
+    Id,
+};
+use twilight_validate::request::{
+    create_guild_ban_delete_message_days as validate_create_guild_ban_delete_message_days,
+    ValidationError,
+};
+
+/// Ban many users from a guild at once.
+///
+/// Created via [`GuildBanResource::post_list`].
+///
+/// [`GuildBanResource::post_list`]: super::GuildBanResource::post_list
+#[derive(Debug)]
+#[must_use = "bulk operations must be configured and executed"]
+pub struct GuildBanBulkCreate<'a> {
+    client: &'a Client,
+    delete_message_days: Option<u16>,
+    guild_id: Id<GuildMarker>,
+    options: BulkOptions<'a, Id<UserMarker>>,
+    user_ids: &'a [Id<UserMarker>],
+}
+
+impl<'a> GuildBanBulkCreate<'a> {
+    pub(super) const fn new(
+        client: &'a Client,
+        guild_id: Id<GuildMarker>,
+        user_ids: &'a [Id<UserMarker>],
+    ) -> Self {
+        Self {
+            client,
+            delete_message_days: None,
+            guild_id,
+            options: BulkOptions::new(),
+            user_ids,
+        }
+    }
+
+    /// Set the number of days of each user's messages to delete.
+    ///
+    /// The number of days must be less than or equal to 7.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`CreateGuildBanDeleteMessageDays`] if the
+    /// number of days is greater than 7.
+    ///
+    /// [`CreateGuildBanDeleteMessageDays`]: twilight_validate::request::ValidationErrorType::CreateGuildBanDeleteMessageDays
+    pub fn delete_message_days(mut self, days: u16) -> Result<Self, ValidationError> {
+        validate_create_guild_ban_delete_message_days(days)?;
+        self.delete_message_days = Some(days);
+
+        Ok(self)
+    }
+
+    /// Set the options of the operation.
+    pub fn options(mut self, options: BulkOptions<'a, Id<UserMarker>>) -> Self {
+        self.options = options;
+
+        self
+    }
+
+    /// Execute the operation, returning the outcome for each user.
+    pub async fn exec(self) -> BulkOutcome<Id<UserMarker>> {
+        let Self {
+            client,
+            delete_message_days,
+            guild_id,
+            options,
+            user_ids,
+        } = self;
+
+        let reason = options.reason;
+
+        options
+            .run(user_ids, |user_id| async move {
+                let mut request = client.create_ban(guild_id, user_id);
+
+                if let Some(days) = delete_message_days {
+                    request = request.delete_message_days(days)?;
+                }
+
+                if let Some(reason) = reason {
+                    request = request.reason(reason)?;
+                }
+
+                request.exec().await?;
+
+                Ok::<_, Error>(())
+            })
+            .await
+    }
+}
+
+/// Unban many users from a guild at once.
+///
+/// Created via [`GuildBanResource::delete_list`].
+///
+/// [`GuildBanResource::delete_list`]: super::GuildBanResource::delete_list
+#[derive(Debug)]
+#[must_use = "bulk operations must be configured and executed"]
+pub struct GuildBanBulkDelete<'a> {
+    client: &'a Client,
+    guild_id: Id<GuildMarker>,
+    options: BulkOptions<'a, Id<UserMarker>>,
+    user_ids: &'a [Id<UserMarker>],
+}
+
+impl<'a> GuildBanBulkDelete<'a> {
+    pub(super) const fn new(
+        client: &'a Client,
+        guild_id: Id<GuildMarker>,
+        user_ids: &'a [Id<UserMarker>],
+    ) -> Self {
+        Self {
+            client,
+            guild_id,
+            options: BulkOptions::new(),
+            user_ids,
+        }
+    }
+
+    /// Set the options of the operation.
+    pub fn options(mut self, options: BulkOptions<'a, Id<UserMarker>>) -> Self {
+        self.options = options;
+
+        self
+    }
+
+    /// Execute the operation, returning the outcome for each user.
+    pub async fn exec(self) -> BulkOutcome<Id<UserMarker>> {
+        let Self {
+            client,
+            guild_id,
+            options,
+            user_ids,
+        } = self;
+
+        let reason = options.reason;
+
+        options
+            .run(user_ids, |user_id| async move {
+                let mut request = client.delete_ban(guild_id, user_id);
+
+                if let Some(reason) = reason {
+                    request = request.reason(reason)?;
+                }
+
+                request.exec().await?;
+
+                Ok::<_, Error>(())
+            })
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GuildBanBulkCreate, GuildBanBulkDelete};
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(GuildBanBulkCreate<'_>: Debug, Send, Sync);
+    assert_impl_all!(GuildBanBulkDelete<'_>: Debug, Send, Sync);
+}
@@ -0,0 +1,102 @@
+use crate::{
+    bulk::{BulkOptions, BulkOutcome},
+    Error,
+};
+use std::collections::HashMap;
+use twilight_http::{client::Client, request::AuditLogReason};
+use twilight_model::{
+    guild::Ban,
+    id::{
+        marker::{GuildMarker, UserMarker},
+        Id,
+    },
+};
+
+/// Apply a list of bans to a guild, such as one previously exported from
+/// another guild.
+///
+/// Each ban's reason is attached as its audit log reason. Bans without a
+/// reason use the reason set via [`BulkOptions::reason`], if any.
+///
+/// Created via [`GuildBanResource::import`].
+///
+/// [`GuildBanResource::import`]: super::GuildBanResource::import
+#[derive(Debug)]
+#[must_use = "bulk operations must be configured and executed"]
+pub struct GuildBanImport<'a> {
+    bans: &'a [Ban],
+    client: &'a Client,
+    guild_id: Id<GuildMarker>,
+    options: BulkOptions<'a, Id<UserMarker>>,
+}
+
+impl<'a> GuildBanImport<'a> {
+    pub(super) const fn new(
+        client: &'a Client,
+        guild_id: Id<GuildMarker>,
+        bans: &'a [Ban],
+    ) -> Self {
+        Self {
+            bans,
+            client,
+            guild_id,
+            options: BulkOptions::new(),
+        }
+    }
+
+    /// Set the options of the operation.
+    pub fn options(mut self, options: BulkOptions<'a, Id<UserMarker>>) -> Self {
+        self.options = options;
+
+        self
+    }
+
+    /// Execute the operation, returning the outcome for each user.
+    pub async fn exec(self) -> BulkOutcome<Id<UserMarker>> {
+        let Self {
+            bans,
+            client,
+            guild_id,
+            options,
+        } = self;
+
+        let reasons = bans
+            .iter()
+            .map(|ban| (ban.user.id, ban.reason.as_deref().or(options.reason)))
+            .collect::<HashMap<_, _>>();
+        let user_ids = bans.iter().map(|ban| ban.user.id).collect::<Vec<_>>();
+
+        options
+            .run(&user_ids, |user_id| {
+                create(client, guild_id, user_id, reasons[&user_id])
+            })
+            .await
+    }
+}
+
+/// Ban a user from a guild with an optional audit log reason.
+pub(super) async fn create(
+    client: &Client,
+    guild_id: Id<GuildMarker>,
+    user_id: Id<UserMarker>,
+    reason: Option<&str>,
+) -> Result<(), Error> {
+    let mut request = client.create_ban(guild_id, user_id);
+
+    if let Some(reason) = reason {
+        request = request.reason(reason)?;
+    }
+
+    request.exec().await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GuildBanImport;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(GuildBanImport<'_>: Debug, Send, Sync);
+}
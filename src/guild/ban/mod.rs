@@ -0,0 +1,159 @@
+mod bulk;
+mod import;
+mod sync;
+
+pub use self::{
+    bulk::{GuildBanBulkCreate, GuildBanBulkDelete},
+    import::GuildBanImport,
+    sync::{BanConflict, GuildBanSync, GuildBanSyncReport},
+};
+
+use crate::{
+    export::{self, ExportFormat},
+    paging, Error, ExecStream,
+};
+use tokio::io::AsyncWrite;
+use twilight_http::{
+    client::Client,
+    request::guild::ban::{CreateBan, DeleteBan, GetBan, GetBans},
+};
+use twilight_model::{
+    guild::Ban,
+    id::{
+        marker::{GuildMarker, UserMarker},
+        Id,
+    },
+};
+
+/// Maximum number of bans that can be retrieved in one page.
+const BAN_PAGE_LIMIT: u16 = 1000;
+
+/// Work with a guild's bans.
+#[derive(Clone, Debug)]
+pub struct GuildBanResource<'a>(&'a Client, Id<GuildMarker>);
+
+impl<'a> GuildBanResource<'a> {
+    /// Create a resource instance to work with a guild's bans.
+    pub const fn new(client: &'a Client, guild_id: Id<GuildMarker>) -> Self {
+        Self(client, guild_id)
+    }
+
+    /// Delete many guild bans at once.
+    ///
+    /// Requests are executed concurrently; refer to [`GuildBanBulkDelete`]
+    /// for configuring the operation.
+    pub const fn delete_list(&self, user_ids: &'a [Id<UserMarker>]) -> GuildBanBulkDelete<'a> {
+        GuildBanBulkDelete::new(self.0, self.1, user_ids)
+    }
+
+    /// Delete a guild ban.
+    pub const fn delete(&self, user_id: Id<UserMarker>) -> DeleteBan<'a> {
+        self.0.delete_ban(self.1, user_id)
+    }
+
+    /// Get a guild ban.
+    pub const fn get(&self, user_id: Id<UserMarker>) -> GetBan<'a> {
+        self.0.ban(self.1, user_id)
+    }
+
+    /// List a guild's bans.
+    pub const fn list(&self) -> GetBans<'a> {
+        self.0.bans(self.1)
+    }
+
+    /// Create a guild ban.
+    pub const fn post(&self, user_id: Id<UserMarker>) -> CreateBan<'a> {
+        self.0.create_ban(self.1, user_id)
+    }
+
+    /// Create many guild bans at once.
+    ///
+    /// Requests are executed concurrently; refer to [`GuildBanBulkCreate`]
+    /// for configuring the operation.
+    pub const fn post_list(&self, user_ids: &'a [Id<UserMarker>]) -> GuildBanBulkCreate<'a> {
+        GuildBanBulkCreate::new(self.0, self.1, user_ids)
+    }
+}
+
+/// RPC calls.
+impl<'a> GuildBanResource<'a> {
+    /// Write all of a guild's bans to a writer, returning the number of bans
+    /// exported.
+    ///
+    /// CSV exports contain each banned user's ID, username, discriminator,
+    /// and the reason for the ban. JSON Lines exports contain each entire
+    /// ban, which can be deserialized and applied to a guild via [`import`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ErrorType::Exporting`] if a ban could not
+    /// be serialized or written.
+    ///
+    /// Returns an error of type [`ErrorType::Request`] if a page of bans could
+    /// not be retrieved.
+    ///
+    /// [`ErrorType::Exporting`]: crate::ErrorType::Exporting
+    /// [`ErrorType::Request`]: crate::ErrorType::Request
+    /// [`import`]: Self::import
+    pub async fn export(
+        &self,
+        writer: impl AsyncWrite + Unpin,
+        format: ExportFormat,
+    ) -> Result<usize, Error> {
+        let header = ["id", "username", "discriminator", "reason"];
+
+        export::export(self.stream(), writer, format, &header, |ban| {
+            vec![
+                ban.user.id.to_string(),
+                ban.user.name.clone(),
+                format!("{:04}", ban.user.discriminator),
+                ban.reason.clone().unwrap_or_default(),
+            ]
+        })
+        .await
+    }
+
+    /// Apply a list of bans to a guild.
+    ///
+    /// Requests are executed concurrently; refer to [`GuildBanImport`] for
+    /// configuring the operation.
+    pub const fn import(&self, bans: &'a [Ban]) -> GuildBanImport<'a> {
+        GuildBanImport::new(self.0, self.1, bans)
+    }
+
+    /// Stream all of a guild's bans, retrieving pages as needed.
+    pub fn stream(&self) -> ExecStream<'a, Ban> {
+        let client = self.0;
+        let guild_id = self.1;
+
+        paging::user_pages(
+            BAN_PAGE_LIMIT,
+            move |after| async move {
+                let mut request = client.bans(guild_id).limit(BAN_PAGE_LIMIT)?;
+
+                if let Some(user_id) = after {
+                    request = request.after(user_id);
+                }
+
+                Ok::<_, Error>(request.exec().await?.models().await?)
+            },
+            |ban| ban.user.id,
+        )
+    }
+
+    /// Ban users banned from the guild in a set of other guilds.
+    ///
+    /// Refer to [`GuildBanSync`] for how bans are synced.
+    pub const fn sync_to(&self, target_guild_ids: &'a [Id<GuildMarker>]) -> GuildBanSync<'a> {
+        GuildBanSync::new(self.0, self.1, target_guild_ids)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GuildBanResource;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(GuildBanResource<'_>: Clone, Debug, Send, Sync);
+}
@@ -0,0 +1,236 @@
+use super::{import, GuildBanResource};
+use crate::{
+    bulk::{BulkOptions, BulkOutcome},
+    Error,
+};
+use futures_util::TryStreamExt;
+use std::collections::{HashMap, HashSet};
+use twilight_http::client::Client;
+use twilight_model::{
+    guild::Ban,
+    id::{
+        marker::{GuildMarker, UserMarker},
+        Id,
+    },
+};
+
+/// User banned from both the source and a target guild for different
+/// reasons.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub struct BanConflict {
+    /// Reason for the ban in the source guild.
+    pub source_reason: Option<String>,
+    /// Reason for the ban in the target guild.
+    pub target_reason: Option<String>,
+    /// ID of the banned user.
+    pub user_id: Id<UserMarker>,
+}
+
+/// Outcome of syncing bans to a target guild.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct GuildBanSyncReport {
+    /// Outcome of adding bans that were missing from the target guild.
+    pub added: BulkOutcome<Id<UserMarker>>,
+    /// Users banned from both guilds for different reasons.
+    ///
+    /// Conflicting bans are left unchanged.
+    pub conflicts: Vec<BanConflict>,
+    /// ID of the target guild.
+    pub guild_id: Id<GuildMarker>,
+    /// IDs of users banned from the target guild but not the source guild.
+    ///
+    /// These bans are left unchanged.
+    pub target_only: Vec<Id<UserMarker>>,
+}
+
+/// Ban users banned from a source guild in a set of target guilds.
+///
+/// Bans are only ever added; bans in target guilds that differ from or are
+/// missing in the source guild are reported but left unchanged. Each added
+/// ban uses the source ban's reason as its audit log reason, or the reason
+/// set via [`BulkOptions::reason`] if the source ban has none.
+///
+/// Target guilds are synced in order, with at most the configured
+/// concurrency of requests in flight in each. The progress of each target
+/// guild is counted separately, and bans that were not added before the
+/// operation was cancelled, including those of target guilds that had not
+/// been synced yet, are reported in [`BulkOutcome::skipped`].
+///
+/// Created via [`GuildBanResource::sync_to`].
+///
+/// [`GuildBanResource::sync_to`]: super::GuildBanResource::sync_to
+#[derive(Debug)]
+#[must_use = "ban syncs must be configured and executed"]
+pub struct GuildBanSync<'a> {
+    client: &'a Client,
+    guild_id: Id<GuildMarker>,
+    options: BulkOptions<'a, Id<UserMarker>>,
+    target_guild_ids: &'a [Id<GuildMarker>],
+}
+
+impl<'a> GuildBanSync<'a> {
+    pub(super) const fn new(
+        client: &'a Client,
+        guild_id: Id<GuildMarker>,
+        target_guild_ids: &'a [Id<GuildMarker>],
+    ) -> Self {
+        Self {
+            client,
+            guild_id,
+            options: BulkOptions::new(),
+            target_guild_ids,
+        }
+    }
+
+    /// Set the options of the operation.
+    pub fn options(mut self, options: BulkOptions<'a, Id<UserMarker>>) -> Self {
+        self.options = options;
+
+        self
+    }
+
+    /// Execute the sync, returning a report for each target guild in order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ErrorType::Request`] if the bans of the
+    /// source guild or a target guild could not be retrieved. Failures to
+    /// add individual bans are reported in [`GuildBanSyncReport::added`].
+    ///
+    /// [`ErrorType::Request`]: crate::ErrorType::Request
+    pub async fn exec(self) -> Result<Vec<GuildBanSyncReport>, Error> {
+        let Self {
+            client,
+            guild_id,
+            options,
+            target_guild_ids,
+        } = self;
+
+        let source = GuildBanResource::new(client, guild_id)
+            .stream()
+            .try_collect::<Vec<_>>()
+            .await?;
+        let reasons = source
+            .iter()
+            .map(|ban| (ban.user.id, ban.reason.as_deref().or(options.reason)))
+            .collect::<HashMap<_, _>>();
+
+        let mut reports = Vec::with_capacity(target_guild_ids.len());
+
+        for &target_guild_id in target_guild_ids {
+            let target = GuildBanResource::new(client, target_guild_id)
+                .stream()
+                .try_collect::<Vec<_>>()
+                .await?;
+
+            let Diff {
+                additions,
+                conflicts,
+                target_only,
+            } = diff(&source, &target);
+
+            let added = options
+                .run(&additions, |user_id| {
+                    import::create(client, target_guild_id, user_id, reasons[&user_id])
+                })
+                .await;
+
+            reports.push(GuildBanSyncReport {
+                added,
+                conflicts,
+                guild_id: target_guild_id,
+                target_only,
+            });
+        }
+
+        Ok(reports)
+    }
+}
+
+/// Differences between the bans of a source and target guild.
+#[derive(Debug, Default, Eq, PartialEq)]
+struct Diff {
+    additions: Vec<Id<UserMarker>>,
+    conflicts: Vec<BanConflict>,
+    target_only: Vec<Id<UserMarker>>,
+}
+
+/// Compare the bans of a source guild to those of a target guild.
+fn diff(source: &[Ban], target: &[Ban]) -> Diff {
+    let targets = target
+        .iter()
+        .map(|ban| (ban.user.id, ban))
+        .collect::<HashMap<_, _>>();
+    let mut diff = Diff::default();
+
+    for ban in source {
+        match targets.get(&ban.user.id) {
+            None => diff.additions.push(ban.user.id),
+            Some(existing) if existing.reason != ban.reason => {
+                diff.conflicts.push(BanConflict {
+                    source_reason: ban.reason.clone(),
+                    target_reason: existing.reason.clone(),
+                    user_id: ban.user.id,
+                });
+            }
+            Some(_) => {}
+        }
+    }
+
+    let sources = source.iter().map(|ban| ban.user.id).collect::<HashSet<_>>();
+    diff.target_only = target
+        .iter()
+        .map(|ban| ban.user.id)
+        .filter(|user_id| !sources.contains(user_id))
+        .collect();
+
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{diff, BanConflict, Diff, GuildBanSync, GuildBanSyncReport};
+    use serde_json::json;
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
+    use twilight_model::{guild::Ban, id::Id};
+
+    assert_impl_all!(BanConflict: Clone, Debug, Eq, Hash, PartialEq, Send, Sync);
+    assert_impl_all!(GuildBanSync<'_>: Debug, Send, Sync);
+    assert_impl_all!(GuildBanSyncReport: Debug, Send, Sync);
+
+    fn ban(id: &str, reason: Option<&str>) -> Result<Ban, serde_json::Error> {
+        serde_json::from_value(json!({
+            "reason": reason,
+            "user": {
+                "avatar": null,
+                "discriminator": "0001",
+                "id": id,
+                "username": "user",
+            },
+        }))
+    }
+
+    #[test]
+    fn diffs() -> Result<(), serde_json::Error> {
+        let source = [ban("1", Some("spam"))?, ban("2", None)?, ban("3", None)?];
+        let target = [ban("2", Some("raid"))?, ban("3", None)?, ban("4", None)?];
+
+        assert_eq!(
+            Diff {
+                additions: vec![Id::new(1)],
+                conflicts: vec![BanConflict {
+                    source_reason: None,
+                    target_reason: Some("raid".to_owned()),
+                    user_id: Id::new(2),
+                }],
+                target_only: vec![Id::new(4)],
+            },
+            diff(&source, &target)
+        );
+
+        Ok(())
+    }
+}
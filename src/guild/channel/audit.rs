@@ -0,0 +1,384 @@
+use crate::{bulk::DEFAULT_CONCURRENCY, channel::ChannelPermissionOverwriteResource, error, Error};
+use futures_util::{
+    future,
+    stream::{self, StreamExt, TryStreamExt},
+};
+use std::collections::{HashMap, HashSet};
+use twilight_http::client::Client;
+use twilight_model::{
+    channel::permission_overwrite::{PermissionOverwrite, PermissionOverwriteType},
+    guild::Permissions,
+    id::{
+        marker::{ChannelMarker, GenericMarker, GuildMarker, RoleMarker, UserMarker},
+        Id,
+    },
+};
+
+/// Permission overwrite of a channel that differs from its parent category.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub struct OverwriteDeviation {
+    /// Channel's overwrite for the target, if it has one.
+    pub channel: Option<PermissionOverwrite>,
+    /// ID of the role or member targeted by the overwrite.
+    pub id: Id<GenericMarker>,
+    /// Parent category's overwrite for the target, if it has one.
+    pub parent: Option<PermissionOverwrite>,
+}
+
+/// Report of a channel's permission overwrites.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct ChannelOverwriteReport {
+    /// ID of the channel.
+    pub channel_id: Id<ChannelMarker>,
+    /// Overwrites that differ from the channel's parent category.
+    ///
+    /// This is empty if the channel does not have a parent category.
+    pub deviations: Vec<OverwriteDeviation>,
+    /// Whether the @everyone role can view the channel.
+    pub everyone_can_view: bool,
+    /// Error that stopped the channel's issues from being fixed, if any.
+    ///
+    /// Fixes made before the error occurred are kept.
+    pub fix_error: Option<Error>,
+    /// Whether the channel's overwrites were changed to fix issues.
+    pub fixed: bool,
+    /// Overwrites targeting roles that have been deleted or members that have
+    /// left the guild.
+    pub stale: Vec<PermissionOverwrite>,
+}
+
+/// Audit the permission overwrites of all of a guild's channels.
+///
+/// Created via [`GuildChannelResource::audit_overwrites`].
+///
+/// [`GuildChannelResource::audit_overwrites`]: super::GuildChannelResource::audit_overwrites
+#[derive(Debug)]
+#[must_use = "audits must be configured and executed"]
+pub struct GuildChannelOverwriteAudit<'a> {
+    client: &'a Client,
+    fix_deviations: bool,
+    fix_stale: bool,
+    guild_id: Id<GuildMarker>,
+}
+
+impl<'a> GuildChannelOverwriteAudit<'a> {
+    pub(super) const fn new(client: &'a Client, guild_id: Id<GuildMarker>) -> Self {
+        Self {
+            client,
+            fix_deviations: false,
+            fix_stale: false,
+            guild_id,
+        }
+    }
+
+    /// Sync channels whose overwrites differ from their parent category with
+    /// the parent.
+    ///
+    /// Refer to [`ChannelPermissionOverwriteResource::sync_with_parent`] for
+    /// how channels are synced.
+    pub const fn fix_deviations(mut self) -> Self {
+        self.fix_deviations = true;
+
+        self
+    }
+
+    /// Delete overwrites targeting roles that have been deleted or members
+    /// that have left the guild.
+    pub const fn fix_stale(mut self) -> Self {
+        self.fix_stale = true;
+
+        self
+    }
+
+    /// Execute the audit, returning a report for each channel.
+    ///
+    /// Reports describe channels as they were before any fixes were applied.
+    /// Fixes are only applied once every channel has been audited, and a
+    /// failed fix is recorded on its channel's report rather than stopping
+    /// the audit.
+    ///
+    /// Stale overwrites are deleted from every channel before any channel is
+    /// synced with its parent, so that a category's stale overwrites aren't
+    /// copied onto its channels.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ErrorType::Request`] if the guild's
+    /// channels, roles, or members could not be retrieved.
+    ///
+    /// [`ErrorType::Request`]: crate::ErrorType::Request
+    pub async fn exec(self) -> Result<Vec<ChannelOverwriteReport>, Error> {
+        let Self {
+            client,
+            fix_deviations,
+            fix_stale,
+            guild_id,
+        } = self;
+
+        let channels = client
+            .guild_channels(guild_id)
+            .exec()
+            .await?
+            .models()
+            .await?;
+        let roles = client.roles(guild_id).exec().await?.models().await?;
+
+        let everyone_permissions = roles
+            .iter()
+            .find(|role| role.id == guild_id.cast())
+            .map_or_else(Permissions::empty, |role| role.permissions);
+        let role_ids = roles.iter().map(|role| role.id).collect::<HashSet<_>>();
+
+        let member_ids = channels
+            .iter()
+            .flat_map(|channel| channel.permission_overwrites.iter().flatten())
+            .filter(|overwrite| overwrite.kind == PermissionOverwriteType::Member)
+            .map(|overwrite| overwrite.id.cast())
+            .collect::<HashSet<Id<UserMarker>>>();
+
+        let departed = stream::iter(member_ids)
+            .map(|user_id| async move {
+                match client.guild_member(guild_id, user_id).exec().await {
+                    Ok(_) => Ok(None),
+                    Err(source) if error::is_not_found(&source) => Ok(Some(user_id)),
+                    Err(source) => Err(Error::from(source)),
+                }
+            })
+            .buffer_unordered(DEFAULT_CONCURRENCY)
+            .try_filter_map(future::ok)
+            .try_collect::<HashSet<_>>()
+            .await?;
+
+        let overwrites_by_channel = channels
+            .iter()
+            .map(|channel| {
+                (
+                    channel.id,
+                    channel.permission_overwrites.as_deref().unwrap_or_default(),
+                )
+            })
+            .collect::<HashMap<_, _>>();
+
+        let mut reports = Vec::with_capacity(channels.len());
+
+        for channel in &channels {
+            let overwrites = overwrites_by_channel[&channel.id];
+
+            let deviations = channel
+                .parent_id
+                .and_then(|parent_id| overwrites_by_channel.get(&parent_id))
+                .map_or_else(Vec::new, |parent| deviations(overwrites, parent));
+
+            let stale = overwrites
+                .iter()
+                .filter(|overwrite| match overwrite.kind {
+                    PermissionOverwriteType::Member => departed.contains(&overwrite.id.cast()),
+                    PermissionOverwriteType::Role => {
+                        !role_ids.contains(&overwrite.id.cast::<RoleMarker>())
+                    }
+                    _ => false,
+                })
+                .cloned()
+                .collect::<Vec<_>>();
+
+            reports.push(ChannelOverwriteReport {
+                channel_id: channel.id,
+                deviations,
+                everyone_can_view: everyone_can_view(overwrites, guild_id, everyone_permissions),
+                fix_error: None,
+                fixed: false,
+                stale,
+            });
+        }
+
+        if fix_stale {
+            for report in &mut reports {
+                if let Err(source) = delete_stale(client, report).await {
+                    report.fix_error = Some(source);
+                }
+            }
+        }
+
+        if fix_deviations {
+            for report in &mut reports {
+                if report.deviations.is_empty() || report.fix_error.is_some() {
+                    continue;
+                }
+
+                let resource = ChannelPermissionOverwriteResource::new(client, report.channel_id);
+
+                match resource.sync_with_parent().await {
+                    Ok(()) => report.fixed = true,
+                    Err(source) => report.fix_error = Some(source),
+                }
+            }
+        }
+
+        Ok(reports)
+    }
+}
+
+/// Delete a channel's stale overwrites, marking its report as fixed once an
+/// overwrite has been deleted.
+async fn delete_stale(client: &Client, report: &mut ChannelOverwriteReport) -> Result<(), Error> {
+    let resource = ChannelPermissionOverwriteResource::new(client, report.channel_id);
+
+    for overwrite in &report.stale {
+        match overwrite.kind {
+            PermissionOverwriteType::Member => {
+                resource.member(overwrite.id.cast()).delete().exec().await?;
+            }
+            PermissionOverwriteType::Role => {
+                resource.role(overwrite.id.cast()).delete().exec().await?;
+            }
+            _ => continue,
+        }
+
+        report.fixed = true;
+    }
+
+    Ok(())
+}
+
+/// Overwrites of a channel that differ from those of its parent.
+fn deviations(
+    overwrites: &[PermissionOverwrite],
+    parent: &[PermissionOverwrite],
+) -> Vec<OverwriteDeviation> {
+    let find = |overwrites: &[PermissionOverwrite], target: &PermissionOverwrite| {
+        overwrites
+            .iter()
+            .find(|overwrite| overwrite.id == target.id && overwrite.kind == target.kind)
+            .cloned()
+    };
+
+    let mut deviations = Vec::new();
+
+    for parent_overwrite in parent {
+        let channel = find(overwrites, parent_overwrite);
+
+        if channel.as_ref() != Some(parent_overwrite) {
+            deviations.push(OverwriteDeviation {
+                channel,
+                id: parent_overwrite.id,
+                parent: Some(parent_overwrite.clone()),
+            });
+        }
+    }
+
+    for overwrite in overwrites {
+        if find(parent, overwrite).is_none() {
+            deviations.push(OverwriteDeviation {
+                channel: Some(overwrite.clone()),
+                id: overwrite.id,
+                parent: None,
+            });
+        }
+    }
+
+    deviations
+}
+
+/// Whether the @everyone role can view a channel with the given overwrites.
+fn everyone_can_view(
+    overwrites: &[PermissionOverwrite],
+    guild_id: Id<GuildMarker>,
+    mut permissions: Permissions,
+) -> bool {
+    if permissions.contains(Permissions::ADMINISTRATOR) {
+        return true;
+    }
+
+    if let Some(overwrite) = overwrites.iter().find(|overwrite| {
+        overwrite.kind == PermissionOverwriteType::Role && overwrite.id == guild_id.cast()
+    }) {
+        permissions.remove(overwrite.deny);
+        permissions.insert(overwrite.allow);
+    }
+
+    permissions.contains(Permissions::VIEW_CHANNEL)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        deviations, everyone_can_view, ChannelOverwriteReport, GuildChannelOverwriteAudit,
+        OverwriteDeviation,
+    };
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_model::{
+        channel::permission_overwrite::{PermissionOverwrite, PermissionOverwriteType},
+        guild::Permissions,
+        id::Id,
+    };
+
+    assert_impl_all!(ChannelOverwriteReport: Debug, Send, Sync);
+    assert_impl_all!(GuildChannelOverwriteAudit<'_>: Debug, Send, Sync);
+    assert_impl_all!(OverwriteDeviation: Clone, Debug, Eq, Send, Sync);
+
+    fn overwrite(id: u64, allow: Permissions, deny: Permissions) -> PermissionOverwrite {
+        PermissionOverwrite {
+            allow,
+            deny,
+            id: Id::new(id),
+            kind: PermissionOverwriteType::Role,
+        }
+    }
+
+    #[test]
+    fn deviations_from_parent() {
+        let same = overwrite(1, Permissions::SEND_MESSAGES, Permissions::empty());
+        let parent_changed = overwrite(2, Permissions::empty(), Permissions::VIEW_CHANNEL);
+        let channel_changed = overwrite(2, Permissions::VIEW_CHANNEL, Permissions::empty());
+        let parent_only = overwrite(3, Permissions::CONNECT, Permissions::empty());
+        let channel_only = overwrite(4, Permissions::SPEAK, Permissions::empty());
+
+        let channel = [same.clone(), channel_changed.clone(), channel_only.clone()];
+        let parent = [same, parent_changed.clone(), parent_only.clone()];
+
+        assert_eq!(
+            vec![
+                OverwriteDeviation {
+                    channel: Some(channel_changed),
+                    id: Id::new(2),
+                    parent: Some(parent_changed),
+                },
+                OverwriteDeviation {
+                    channel: None,
+                    id: Id::new(3),
+                    parent: Some(parent_only),
+                },
+                OverwriteDeviation {
+                    channel: Some(channel_only),
+                    id: Id::new(4),
+                    parent: None,
+                },
+            ],
+            deviations(&channel, &parent)
+        );
+        assert!(deviations(&channel, &channel).is_empty());
+    }
+
+    #[test]
+    fn everyone_viewing() {
+        let guild_id = Id::new(1);
+        let view = Permissions::VIEW_CHANNEL;
+        let denied = [overwrite(1, Permissions::empty(), view)];
+        let allowed = [overwrite(1, view, Permissions::empty())];
+        let other_role = [overwrite(2, Permissions::empty(), view)];
+
+        assert!(everyone_can_view(&[], guild_id, view));
+        assert!(!everyone_can_view(&[], guild_id, Permissions::empty()));
+        assert!(!everyone_can_view(&denied, guild_id, view));
+        assert!(everyone_can_view(&allowed, guild_id, Permissions::empty()));
+        assert!(everyone_can_view(&other_role, guild_id, view));
+        assert!(everyone_can_view(
+            &denied,
+            guild_id,
+            Permissions::ADMINISTRATOR
+        ));
+    }
+}
@@ -1,3 +1,7 @@
+mod audit;
+
+pub use self::audit::{ChannelOverwriteReport, GuildChannelOverwriteAudit, OverwriteDeviation};
+
 use twilight_http::{
     client::Client,
     request::guild::{
@@ -40,6 +44,16 @@ impl<'a> GuildChannelResource<'a> {
     }
 }
 
+/// RPC calls.
+impl<'a> GuildChannelResource<'a> {
+    /// Audit the permission overwrites of all of a guild's channels.
+    ///
+    /// Refer to [`GuildChannelOverwriteAudit`] for what is reported.
+    pub const fn audit_overwrites(&self) -> GuildChannelOverwriteAudit<'a> {
+        GuildChannelOverwriteAudit::new(self.0, self.1)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::GuildChannelResource;
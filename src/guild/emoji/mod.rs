@@ -1,3 +1,10 @@
+mod sync;
+
+pub use self::sync::{
+    EmojiFileRejection, EmojiSyncPlan, EmojiSyncReport, GuildEmojiSync, RejectedEmojiFile,
+};
+
+use std::path::Path;
 use twilight_http::{
     client::Client,
     request::guild::emoji::{CreateEmoji, DeleteEmoji, GetEmoji, GetEmojis, UpdateEmoji},
@@ -43,6 +50,17 @@ impl<'a> GuildEmojiResource<'a> {
     }
 }
 
+/// RPC calls.
+impl<'a> GuildEmojiResource<'a> {
+    /// Sync the guild's emojis with a directory of PNG and GIF images.
+    ///
+    /// Emoji names are derived from the filenames. Use
+    /// [`GuildEmojiSync::plan`] to preview the changes without making them.
+    pub const fn sync_from_dir(&self, path: &'a Path) -> GuildEmojiSync<'a> {
+        GuildEmojiSync::new(self.0, self.1, path)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::GuildEmojiResource;
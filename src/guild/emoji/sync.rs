@@ -0,0 +1,491 @@
+use crate::{image, Error};
+use std::{
+    ffi::OsStr,
+    path::{Path, PathBuf},
+};
+use tokio::fs;
+use twilight_http::{client::Client, request::AuditLogReason};
+use twilight_model::{
+    guild::Emoji,
+    id::{
+        marker::{EmojiMarker, GuildMarker, RoleMarker},
+        Id,
+    },
+};
+use twilight_validate::request::{audit_reason as validate_audit_reason, ValidationError};
+
+/// Maximum size of an emoji image, in bytes.
+const EMOJI_SIZE_LIMIT: u64 = 256 * 1024;
+
+/// Maximum length of an emoji name.
+const EMOJI_NAME_LENGTH_MAX: usize = 32;
+
+/// Minimum length of an emoji name.
+const EMOJI_NAME_LENGTH_MIN: usize = 2;
+
+/// Reason an image file in an emoji directory was not used.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum EmojiFileRejection {
+    /// Another file has already been used for an emoji with the same name.
+    DuplicateName,
+    /// Filename is not a valid emoji name.
+    ///
+    /// Emoji names must be between 2 and 32 characters long and only contain
+    /// alphanumeric characters and underscores.
+    InvalidName,
+    /// Image is larger than 256 KiB.
+    TooLarge {
+        /// Size of the image, in bytes.
+        size: u64,
+    },
+    /// Contents of the file are not a PNG or GIF image.
+    UnsupportedFormat,
+}
+
+/// Image file in an emoji directory that was not used.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub struct RejectedEmojiFile {
+    /// Path to the file.
+    pub path: PathBuf,
+    /// Reason the file was not used.
+    pub reason: EmojiFileRejection,
+}
+
+/// Changes needed for a guild's emojis to match a directory.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct EmojiSyncPlan {
+    /// Names of emojis to create.
+    pub create: Vec<String>,
+    /// Emojis to delete because they have no matching file.
+    pub delete: Vec<Emoji>,
+    /// Image files that can't be used.
+    pub rejected: Vec<RejectedEmojiFile>,
+    /// Emojis to replace with their matching file.
+    pub replace: Vec<Emoji>,
+    /// Emojis to leave unchanged.
+    pub unchanged: Vec<Emoji>,
+}
+
+/// Changes made for a guild's emojis to match a directory.
+#[derive(Debug, Default)]
+#[non_exhaustive]
+pub struct EmojiSyncReport {
+    /// Emojis that were created.
+    pub created: Vec<Emoji>,
+    /// IDs of emojis that were deleted.
+    ///
+    /// This includes emojis that were replaced.
+    pub deleted: Vec<Id<EmojiMarker>>,
+    /// Error that stopped the sync, if any.
+    ///
+    /// Changes made before the error occurred are kept and reported.
+    pub error: Option<Error>,
+    /// Image files that weren't used.
+    pub rejected: Vec<RejectedEmojiFile>,
+    /// Emojis that were created to replace existing emojis.
+    pub replaced: Vec<Emoji>,
+}
+
+/// Emoji image read from a directory.
+#[derive(Debug)]
+struct EmojiFile {
+    /// Image as a Data URI.
+    image: Vec<u8>,
+    name: String,
+}
+
+/// Create and delete a guild's emojis so that they match a directory of PNG
+/// and GIF images.
+///
+/// Emoji names are taken from the filenames without their extensions, and
+/// files without a `png` or `gif` extension are ignored. Files that are not
+/// valid emojis are reported rather than causing the sync to fail.
+///
+/// Discord doesn't allow an emoji's image to be changed and only returns the
+/// ID of an emoji's image, so emojis with the same name as a file are left
+/// unchanged unless [`replace_existing`] is enabled. Replaced emojis are
+/// created again with the same roles before the existing emoji is deleted, so
+/// they have a new ID, and uses of the old emoji in messages no longer
+/// render. Emojis managed by an integration are never changed.
+///
+/// Created via [`GuildEmojiResource::sync_from_dir`].
+///
+/// [`GuildEmojiResource::sync_from_dir`]: super::GuildEmojiResource::sync_from_dir
+/// [`replace_existing`]: Self::replace_existing
+#[derive(Debug)]
+#[must_use = "emoji syncs must be configured and executed"]
+pub struct GuildEmojiSync<'a> {
+    client: &'a Client,
+    delete_missing: bool,
+    guild_id: Id<GuildMarker>,
+    path: &'a Path,
+    reason: Option<&'a str>,
+    replace_existing: bool,
+}
+
+impl<'a> GuildEmojiSync<'a> {
+    pub(super) const fn new(client: &'a Client, guild_id: Id<GuildMarker>, path: &'a Path) -> Self {
+        Self {
+            client,
+            delete_missing: true,
+            guild_id,
+            path,
+            reason: None,
+            replace_existing: false,
+        }
+    }
+
+    /// Set whether to delete emojis that have no matching file.
+    ///
+    /// Defaults to true.
+    pub const fn delete_missing(mut self, delete_missing: bool) -> Self {
+        self.delete_missing = delete_missing;
+
+        self
+    }
+
+    /// Attach an audit log reason to each change.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`AuditReason`] if the length is invalid.
+    ///
+    /// [`AuditReason`]: twilight_validate::request::ValidationErrorType::AuditReason
+    pub fn reason(mut self, reason: &'a str) -> Result<Self, ValidationError> {
+        validate_audit_reason(reason)?;
+        self.reason = Some(reason);
+
+        Ok(self)
+    }
+
+    /// Set whether to replace emojis that have a matching file.
+    ///
+    /// Defaults to false.
+    pub const fn replace_existing(mut self, replace_existing: bool) -> Self {
+        self.replace_existing = replace_existing;
+
+        self
+    }
+
+    /// Determine the changes the sync would make without making them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ErrorType::Reading`] if the directory or a
+    /// file in it could not be read.
+    ///
+    /// Returns an error of type [`ErrorType::Request`] if the guild's emojis
+    /// could not be retrieved.
+    ///
+    /// [`ErrorType::Reading`]: crate::ErrorType::Reading
+    /// [`ErrorType::Request`]: crate::ErrorType::Request
+    pub async fn plan(self) -> Result<EmojiSyncPlan, Error> {
+        Ok(self.prepare().await?.0)
+    }
+
+    /// Execute the sync, returning the changes that were made.
+    ///
+    /// Changes are made one at a time, and the sync stops at the first change
+    /// that fails. The error is recorded in [`EmojiSyncReport::error`]
+    /// alongside the changes made before it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ErrorType::Reading`] if the directory or a
+    /// file in it could not be read.
+    ///
+    /// Returns an error of type [`ErrorType::Request`] if the guild's emojis
+    /// could not be retrieved.
+    ///
+    /// [`ErrorType::Reading`]: crate::ErrorType::Reading
+    /// [`ErrorType::Request`]: crate::ErrorType::Request
+    pub async fn exec(self) -> Result<EmojiSyncReport, Error> {
+        let (plan, files) = self.prepare().await?;
+
+        let Self {
+            client,
+            guild_id,
+            reason,
+            ..
+        } = self;
+
+        let mut report = EmojiSyncReport {
+            rejected: plan.rejected.clone(),
+            ..EmojiSyncReport::default()
+        };
+
+        let apply = Apply {
+            client,
+            files: &files,
+            guild_id,
+            reason,
+        };
+
+        if let Err(source) = apply.run(&plan, &mut report).await {
+            report.error = Some(source);
+        }
+
+        Ok(report)
+    }
+
+    /// Read the directory and the guild's emojis and plan the changes to
+    /// make.
+    async fn prepare(&self) -> Result<(EmojiSyncPlan, Vec<EmojiFile>), Error> {
+        let (files, rejected) = read_dir(self.path).await?;
+        let emojis = self
+            .client
+            .emojis(self.guild_id)
+            .exec()
+            .await?
+            .models()
+            .await?;
+
+        let plan = plan(
+            &files,
+            &emojis,
+            rejected,
+            self.delete_missing,
+            self.replace_existing,
+        );
+
+        Ok((plan, files))
+    }
+}
+
+/// Changes being applied to a guild's emojis.
+struct Apply<'a> {
+    client: &'a Client,
+    files: &'a [EmojiFile],
+    guild_id: Id<GuildMarker>,
+    reason: Option<&'a str>,
+}
+
+impl Apply<'_> {
+    /// Apply a plan, recording each change in the report as it is made.
+    async fn run(&self, plan: &EmojiSyncPlan, report: &mut EmojiSyncReport) -> Result<(), Error> {
+        for emoji in &plan.delete {
+            self.delete(emoji.id).await?;
+            report.deleted.push(emoji.id);
+        }
+
+        // Emoji names don't need to be unique, so the replacement is created
+        // before the existing emoji is deleted.
+        for emoji in &plan.replace {
+            report
+                .replaced
+                .push(self.create(&emoji.name, &emoji.roles).await?);
+
+            self.delete(emoji.id).await?;
+            report.deleted.push(emoji.id);
+        }
+
+        for name in &plan.create {
+            report.created.push(self.create(name, &[]).await?);
+        }
+
+        Ok(())
+    }
+
+    /// Create an emoji from the file with its name.
+    async fn create(&self, name: &str, roles: &[Id<RoleMarker>]) -> Result<Emoji, Error> {
+        let image = self
+            .files
+            .iter()
+            .find(|file| file.name == name)
+            .map(|file| file.image.as_slice())
+            .unwrap_or_default();
+
+        let mut request = self.client.create_emoji(self.guild_id, name, image);
+
+        if !roles.is_empty() {
+            request = request.roles(roles);
+        }
+
+        if let Some(reason) = self.reason {
+            request = request.reason(reason)?;
+        }
+
+        Ok(request.exec().await?.model().await?)
+    }
+
+    /// Delete an emoji.
+    async fn delete(&self, emoji_id: Id<EmojiMarker>) -> Result<(), Error> {
+        let mut request = self.client.delete_emoji(self.guild_id, emoji_id);
+
+        if let Some(reason) = self.reason {
+            request = request.reason(reason)?;
+        }
+
+        request.exec().await?;
+
+        Ok(())
+    }
+}
+
+/// Read the emoji images in a directory, in order of their paths.
+async fn read_dir(path: &Path) -> Result<(Vec<EmojiFile>, Vec<RejectedEmojiFile>), Error> {
+    let mut entries = fs::read_dir(path).await.map_err(Error::reading)?;
+    let mut paths = Vec::new();
+
+    while let Some(entry) = entries.next_entry().await.map_err(Error::reading)? {
+        let path = entry.path();
+        let is_image = path
+            .extension()
+            .and_then(OsStr::to_str)
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("gif") || ext.eq_ignore_ascii_case("png"));
+
+        if !is_image {
+            continue;
+        }
+
+        let metadata = entry.metadata().await.map_err(Error::reading)?;
+
+        if metadata.is_file() {
+            paths.push((path, metadata.len()));
+        }
+    }
+
+    paths.sort();
+
+    let mut files = Vec::<EmojiFile>::new();
+    let mut rejected = Vec::new();
+
+    for (path, size) in paths {
+        let name = path.file_stem().and_then(OsStr::to_str).unwrap_or_default();
+
+        let reason = if !is_valid_name(name) {
+            EmojiFileRejection::InvalidName
+        } else if files.iter().any(|file| file.name == name) {
+            EmojiFileRejection::DuplicateName
+        } else if size > EMOJI_SIZE_LIMIT {
+            EmojiFileRejection::TooLarge { size }
+        } else {
+            let image = fs::read(&path).await.map_err(Error::reading)?;
+
+            if let Some(mime) = image::mime_type(&image) {
+                files.push(EmojiFile {
+                    image: image::data_uri(mime, &image),
+                    name: name.to_owned(),
+                });
+
+                continue;
+            }
+
+            EmojiFileRejection::UnsupportedFormat
+        };
+
+        rejected.push(RejectedEmojiFile { path, reason });
+    }
+
+    Ok((files, rejected))
+}
+
+/// Plan the changes needed for a guild's emojis to match a set of files.
+fn plan(
+    files: &[EmojiFile],
+    emojis: &[Emoji],
+    rejected: Vec<RejectedEmojiFile>,
+    delete_missing: bool,
+    replace_existing: bool,
+) -> EmojiSyncPlan {
+    let mut plan = EmojiSyncPlan {
+        rejected,
+        ..EmojiSyncPlan::default()
+    };
+
+    for emoji in emojis.iter().filter(|emoji| !emoji.managed) {
+        let has_file = files.iter().any(|file| file.name == emoji.name);
+
+        if has_file && replace_existing {
+            plan.replace.push(emoji.clone());
+        } else if !has_file && delete_missing {
+            plan.delete.push(emoji.clone());
+        } else {
+            plan.unchanged.push(emoji.clone());
+        }
+    }
+
+    plan.create = files
+        .iter()
+        .filter(|file| !emojis.iter().any(|emoji| emoji.name == file.name))
+        .map(|file| file.name.clone())
+        .collect();
+
+    plan
+}
+
+/// Whether a name is a valid emoji name.
+fn is_valid_name(name: &str) -> bool {
+    (EMOJI_NAME_LENGTH_MIN..=EMOJI_NAME_LENGTH_MAX).contains(&name.chars().count())
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        is_valid_name, plan, EmojiFile, EmojiFileRejection, EmojiSyncPlan, EmojiSyncReport,
+        GuildEmojiSync, RejectedEmojiFile,
+    };
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
+    use twilight_model::{guild::Emoji, id::Id};
+
+    assert_impl_all!(EmojiFileRejection: Clone, Copy, Debug, Eq, Hash, PartialEq, Send, Sync);
+    assert_impl_all!(EmojiSyncPlan: Clone, Debug, Default, Eq, Send, Sync);
+    assert_impl_all!(EmojiSyncReport: Debug, Default, Send, Sync);
+    assert_impl_all!(GuildEmojiSync<'_>: Debug, Send, Sync);
+    assert_impl_all!(RejectedEmojiFile: Clone, Debug, Eq, Hash, PartialEq, Send, Sync);
+
+    #[test]
+    fn names() {
+        assert!(is_valid_name("party_parrot"));
+        assert!(is_valid_name("ok"));
+        assert!(!is_valid_name("a"));
+        assert!(!is_valid_name("party-parrot"));
+        assert!(!is_valid_name(&"a".repeat(33)));
+    }
+
+    #[test]
+    fn plans() {
+        let file = |name: &str| EmojiFile {
+            image: Vec::new(),
+            name: name.to_owned(),
+        };
+        let emoji = |id, name: &str, managed| Emoji {
+            animated: false,
+            available: true,
+            id: Id::new(id),
+            managed,
+            name: name.to_owned(),
+            require_colons: true,
+            roles: Vec::new(),
+            user: None,
+        };
+        let ids = |emojis: &[Emoji]| {
+            emojis
+                .iter()
+                .map(|emoji| emoji.id.get())
+                .collect::<Vec<_>>()
+        };
+
+        let files = [file("kept"), file("new")];
+        let emojis = [
+            emoji(1, "kept", false),
+            emoji(2, "old", false),
+            emoji(3, "twitch", true),
+        ];
+
+        let planned = plan(&files, &emojis, Vec::new(), true, false);
+        assert_eq!(vec!["new".to_owned()], planned.create);
+        assert_eq!(vec![2], ids(&planned.delete));
+        assert!(planned.replace.is_empty());
+        assert_eq!(vec![1], ids(&planned.unchanged));
+
+        let planned = plan(&files, &emojis, Vec::new(), false, true);
+        assert_eq!(vec![1], ids(&planned.replace));
+        assert_eq!(vec![2], ids(&planned.unchanged));
+    }
+}
@@ -1,12 +1,49 @@
+use crate::Error;
 use twilight_http::{
     client::Client,
     request::guild::integration::{DeleteGuildIntegration, GetGuildIntegrations},
 };
-use twilight_model::id::{
-    marker::{GuildMarker, IntegrationMarker},
-    Id,
+use twilight_model::{
+    guild::GuildIntegration,
+    id::{
+        marker::{ApplicationMarker, GuildMarker, IntegrationMarker},
+        Id,
+    },
 };
 
+/// A guild's integrations, grouped by platform.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct GuildIntegrationGroups {
+    /// Integrations of the `discord` type, such as bots.
+    pub discord: Vec<GuildIntegration>,
+    /// Integrations of any other type.
+    pub other: Vec<GuildIntegration>,
+    /// Integrations of the `twitch` type.
+    pub twitch: Vec<GuildIntegration>,
+    /// Integrations of the `youtube` type.
+    pub youtube: Vec<GuildIntegration>,
+}
+
+impl From<Vec<GuildIntegration>> for GuildIntegrationGroups {
+    fn from(integrations: Vec<GuildIntegration>) -> Self {
+        let mut groups = Self::default();
+
+        for integration in integrations {
+            let group = match integration.kind.as_str() {
+                "discord" => &mut groups.discord,
+                "twitch" => &mut groups.twitch,
+                "youtube" => &mut groups.youtube,
+                _ => &mut groups.other,
+            };
+
+            group.push(integration);
+        }
+
+        groups
+    }
+}
+
 /// Work with a guild's integrations.
 #[derive(Clone, Debug)]
 pub struct GuildIntegrationResource<'a>(&'a Client, Id<GuildMarker>);
@@ -31,11 +68,121 @@ impl<'a> GuildIntegrationResource<'a> {
     }
 }
 
+/// RPC calls.
+impl GuildIntegrationResource<'_> {
+    /// Get a guild's integration, if there is one.
+    ///
+    /// The integration is retrieved from the guild's list of integrations.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ErrorType::Request`] if the guild's
+    /// integrations could not be retrieved.
+    ///
+    /// [`ErrorType::Request`]: crate::ErrorType::Request
+    pub async fn get(
+        &self,
+        integration_id: Id<IntegrationMarker>,
+    ) -> Result<Option<GuildIntegration>, Error> {
+        self.find(|integration| integration.id == integration_id)
+            .await
+    }
+
+    /// Get a guild's integration for an application, if there is one.
+    ///
+    /// The integration is retrieved from the guild's list of integrations.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ErrorType::Request`] if the guild's
+    /// integrations could not be retrieved.
+    ///
+    /// [`ErrorType::Request`]: crate::ErrorType::Request
+    pub async fn get_by_application(
+        &self,
+        application_id: Id<ApplicationMarker>,
+    ) -> Result<Option<GuildIntegration>, Error> {
+        self.find(|integration| {
+            integration
+                .application
+                .as_ref()
+                .is_some_and(|application| application.id == application_id)
+        })
+        .await
+    }
+
+    /// List a guild's integrations grouped by platform.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ErrorType::Request`] if the guild's
+    /// integrations could not be retrieved.
+    ///
+    /// [`ErrorType::Request`]: crate::ErrorType::Request
+    pub async fn list_grouped(&self) -> Result<GuildIntegrationGroups, Error> {
+        let integrations = self
+            .0
+            .guild_integrations(self.1)
+            .exec()
+            .await?
+            .models()
+            .await?;
+
+        Ok(integrations.into())
+    }
+
+    /// Remove an application's bot from a guild by deleting its integration,
+    /// returning the deleted integration.
+    ///
+    /// Returns `None` if the application does not have an integration in the
+    /// guild.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ErrorType::Request`] if the guild's
+    /// integrations could not be retrieved or the integration could not be
+    /// deleted.
+    ///
+    /// [`ErrorType::Request`]: crate::ErrorType::Request
+    pub async fn remove_bot(
+        &self,
+        application_id: Id<ApplicationMarker>,
+    ) -> Result<Option<GuildIntegration>, Error> {
+        let Some(integration) = self.get_by_application(application_id).await? else {
+            return Ok(None);
+        };
+
+        self.0
+            .delete_guild_integration(self.1, integration.id)
+            .exec()
+            .await?;
+
+        Ok(Some(integration))
+    }
+
+    /// Find the first of a guild's integrations that matches a predicate.
+    async fn find(
+        &self,
+        predicate: impl Fn(&GuildIntegration) -> bool,
+    ) -> Result<Option<GuildIntegration>, Error> {
+        let integrations = self
+            .0
+            .guild_integrations(self.1)
+            .exec()
+            .await?
+            .models()
+            .await?;
+
+        Ok(integrations.into_iter().find(predicate))
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::GuildIntegrationResource;
+    use super::{GuildIntegrationGroups, GuildIntegrationResource};
     use static_assertions::assert_impl_all;
     use std::fmt::Debug;
 
+    assert_impl_all!(GuildIntegrationGroups: Clone, Debug, Default, Eq, Send, Sync);
     assert_impl_all!(GuildIntegrationResource<'_>: Clone, Debug, Send, Sync);
 }
@@ -0,0 +1,267 @@
+use super::GuildMemberResource;
+use crate::{
+    export::{self, ExportFormat},
+    Error, ExecStream,
+};
+use futures_util::{future, TryStreamExt};
+use tokio::io::AsyncWrite;
+use twilight_http::client::Client;
+use twilight_model::{
+    guild::Member,
+    id::{
+        marker::{GuildMarker, RoleMarker},
+        Id,
+    },
+    util::Timestamp,
+};
+
+/// Filter all of a guild's members and optionally export them.
+///
+/// All of the configured filters must match for a member to be included.
+///
+/// Created via [`GuildMemberResource::filter`].
+///
+/// # Examples
+///
+/// Export members that have not passed membership screening and joined within
+/// the last week as CSV:
+///
+/// ```rust,no_run
+/// use std::{
+///     env,
+///     time::{Duration, SystemTime},
+/// };
+/// use tokio::fs::File;
+/// use twilight_http_resource::{export::ExportFormat, timestamp, Resource};
+/// use twilight_http::Client;
+/// use twilight_model::id::Id;
+///
+/// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new(env::var("DISCORD_TOKEN")?);
+/// let week_ago =
+///     timestamp::from_system_time(SystemTime::now() - Duration::from_secs(7 * 24 * 60 * 60))?;
+///
+/// let file = File::create("pending.csv").await?;
+/// let count = client
+///     .guilds()
+///     .members(Id::new(1))
+///     .filter()
+///     .joined_after(week_ago)
+///     .pending(true)
+///     .export(file, ExportFormat::Csv)
+///     .await?;
+///
+/// println!("exported {} members", count);
+/// # Ok(()) }
+/// ```
+#[derive(Clone, Debug)]
+#[must_use = "filters must be configured and executed"]
+pub struct GuildMemberFilter<'a> {
+    client: &'a Client,
+    guild_id: Id<GuildMarker>,
+    joined_after: Option<Timestamp>,
+    joined_before: Option<Timestamp>,
+    no_roles: bool,
+    pending: Option<bool>,
+    roles: Vec<Id<RoleMarker>>,
+    username: Option<&'a str>,
+}
+
+impl<'a> GuildMemberFilter<'a> {
+    pub(super) const fn new(client: &'a Client, guild_id: Id<GuildMarker>) -> Self {
+        Self {
+            client,
+            guild_id,
+            joined_after: None,
+            joined_before: None,
+            no_roles: false,
+            pending: None,
+            roles: Vec::new(),
+            username: None,
+        }
+    }
+
+    /// Only include members that joined after a point in time.
+    pub const fn joined_after(mut self, timestamp: Timestamp) -> Self {
+        self.joined_after = Some(timestamp);
+
+        self
+    }
+
+    /// Only include members that joined before a point in time.
+    pub const fn joined_before(mut self, timestamp: Timestamp) -> Self {
+        self.joined_before = Some(timestamp);
+
+        self
+    }
+
+    /// Only include members that have no roles.
+    pub const fn no_roles(mut self) -> Self {
+        self.no_roles = true;
+
+        self
+    }
+
+    /// Only include members whose membership screening status matches.
+    ///
+    /// Members that have yet to pass membership screening are pending.
+    pub const fn pending(mut self, pending: bool) -> Self {
+        self.pending = Some(pending);
+
+        self
+    }
+
+    /// Only include members that have a role.
+    ///
+    /// This may be called multiple times, in which case members must have all
+    /// of the roles.
+    pub fn role(mut self, role_id: Id<RoleMarker>) -> Self {
+        self.roles.push(role_id);
+
+        self
+    }
+
+    /// Only include members whose username matches a pattern.
+    ///
+    /// Patterns are matched case-insensitively against the entire username.
+    /// A `*` matches any number of characters and a `?` matches exactly one
+    /// character; for example, `*bot*` matches any username containing "bot".
+    pub const fn username(mut self, pattern: &'a str) -> Self {
+        self.username = Some(pattern);
+
+        self
+    }
+
+    /// Whether a member matches all of the configured filters.
+    pub fn matches(&self, member: &Member) -> bool {
+        let joined_at = member.joined_at.as_micros();
+
+        self.joined_after
+            .map_or(true, |after| joined_at > after.as_micros())
+            && self
+                .joined_before
+                .map_or(true, |before| joined_at < before.as_micros())
+            && (!self.no_roles || member.roles.is_empty())
+            && self
+                .pending
+                .map_or(true, |pending| member.pending == pending)
+            && self
+                .roles
+                .iter()
+                .all(|role_id| member.roles.contains(role_id))
+            && self
+                .username
+                .map_or(true, |pattern| glob_matches(pattern, &member.user.name))
+    }
+
+    /// Stream all of the guild's members that match the filters.
+    pub fn stream(self) -> ExecStream<'a, Member> {
+        let members = GuildMemberResource::new(self.client, self.guild_id).stream();
+
+        Box::pin(members.try_filter(move |member| future::ready(self.matches(member))))
+    }
+
+    /// Write all of the guild's members that match the filters to a writer,
+    /// returning the number of members written.
+    ///
+    /// CSV exports contain the columns `id`, `username`, `discriminator`,
+    /// `nick`, `joined_at`, `pending`, and `roles`, where roles are separated
+    /// by spaces.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ErrorType::Exporting`] if a member could not
+    /// be serialized or written.
+    ///
+    /// Returns an error of type [`ErrorType::Request`] if a page of members
+    /// could not be retrieved.
+    ///
+    /// [`ErrorType::Exporting`]: crate::ErrorType::Exporting
+    /// [`ErrorType::Request`]: crate::ErrorType::Request
+    pub async fn export(
+        self,
+        writer: impl AsyncWrite + Unpin,
+        format: ExportFormat,
+    ) -> Result<usize, Error> {
+        let header = [
+            "id",
+            "username",
+            "discriminator",
+            "nick",
+            "joined_at",
+            "pending",
+            "roles",
+        ];
+
+        export::export(self.stream(), writer, format, &header, |member| {
+            let roles = member
+                .roles
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(" ");
+
+            vec![
+                member.user.id.to_string(),
+                member.user.name.clone(),
+                format!("{:04}", member.user.discriminator),
+                member.nick.clone().unwrap_or_default(),
+                member.joined_at.iso_8601().to_string(),
+                member.pending.to_string(),
+                roles,
+            ]
+        })
+        .await
+    }
+}
+
+/// Whether a value matches a pattern, where `*` matches any number of
+/// characters and `?` matches one character, ignoring case.
+fn glob_matches(pattern: &str, value: &str) -> bool {
+    let pattern = pattern.to_lowercase().chars().collect::<Vec<_>>();
+    let value = value.to_lowercase().chars().collect::<Vec<_>>();
+
+    let (mut p, mut v) = (0, 0);
+    let mut backtrack = None;
+
+    while v < value.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, v));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == value[v] => {
+                p += 1;
+                v += 1;
+            }
+            _ => match backtrack {
+                Some((star, matched)) => {
+                    p = star + 1;
+                    v = matched + 1;
+                    backtrack = Some((star, matched + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{glob_matches, GuildMemberFilter};
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(GuildMemberFilter<'_>: Clone, Debug, Send, Sync);
+
+    #[test]
+    fn glob() {
+        assert!(glob_matches("*bot*", "SpamBot3000"));
+        assert!(glob_matches("user?", "User1"));
+        assert!(glob_matches("*", ""));
+        assert!(!glob_matches("user?", "user"));
+        assert!(!glob_matches("a*b", "acbd"));
+    }
+}
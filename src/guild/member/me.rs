@@ -0,0 +1,44 @@
+use twilight_http::{
+    client::Client,
+    request::{
+        guild::UpdateCurrentMember,
+        user::{GetCurrentUserGuildMember, LeaveGuild},
+    },
+};
+use twilight_model::id::{marker::GuildMarker, Id};
+
+/// Work with the current user's membership in a guild.
+#[derive(Clone, Debug)]
+pub struct GuildMemberMeResource<'a>(&'a Client, Id<GuildMarker>);
+
+impl<'a> GuildMemberMeResource<'a> {
+    /// Create a resource instance to work with the current user's membership
+    /// in a guild.
+    pub const fn new(client: &'a Client, guild_id: Id<GuildMarker>) -> Self {
+        Self(client, guild_id)
+    }
+
+    /// Leave the guild.
+    pub const fn delete(&self) -> LeaveGuild<'a> {
+        self.0.leave_guild(self.1)
+    }
+
+    /// Get the current member.
+    pub const fn get(&self) -> GetCurrentUserGuildMember<'a> {
+        self.0.current_user_guild_member(self.1)
+    }
+
+    /// Update the current member, such as its nickname.
+    pub const fn patch(&self) -> UpdateCurrentMember<'a> {
+        self.0.update_current_member(self.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GuildMemberMeResource;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(GuildMemberMeResource<'_>: Clone, Debug, Send, Sync);
+}
@@ -1,9 +1,22 @@
 //! Work with guild members and their relationships.
 
+mod filter;
+mod me;
 mod role;
 
-pub use self::role::GuildMemberRoleResource;
+pub use self::{
+    filter::GuildMemberFilter, me::GuildMemberMeResource, role::GuildMemberRoleResource,
+};
 
+use crate::{bulk::DEFAULT_CONCURRENCY, paging, timestamp, Error, ExecStream};
+use futures_util::{
+    future,
+    stream::{self, StreamExt, TryStreamExt},
+};
+use std::{
+    collections::HashSet,
+    time::{Duration, SystemTime},
+};
 use twilight_http::{
     client::Client,
     request::guild::member::{
@@ -11,11 +24,23 @@ use twilight_http::{
         UpdateGuildMember,
     },
 };
-use twilight_model::id::{
-    marker::{GuildMarker, UserMarker},
-    Id,
+use twilight_model::{
+    guild::Member,
+    id::{
+        marker::{GuildMarker, UserMarker},
+        Id,
+    },
 };
 
+/// Maximum number of members that can be retrieved in one page.
+const MEMBER_PAGE_LIMIT: u16 = 1000;
+
+/// Longest timeout duration used to compute a timeout's expiry.
+///
+/// Longer durations are capped to this so that the expiry can be computed;
+/// they still fail validation, since timeouts can be at most 28 days.
+const TIMEOUT_DURATION_CAP: Duration = Duration::from_secs(365 * 24 * 60 * 60);
+
 /// Work with a guild's members.
 #[derive(Clone, Debug)]
 pub struct GuildMemberResource<'a>(&'a Client, Id<GuildMarker>);
@@ -54,13 +79,149 @@ impl<'a> GuildMemberResource<'a> {
 
 /// RPC calls.
 impl<'a> GuildMemberResource<'a> {
-    /// Search a guild's members.
-    pub const fn search(
+    /// Filter all of a guild's members.
+    ///
+    /// Refer to [`GuildMemberFilter`] for the available filters.
+    pub const fn filter(&self) -> GuildMemberFilter<'a> {
+        GuildMemberFilter::new(self.0, self.1)
+    }
+
+    /// Remove a guild member's timeout.
+    ///
+    /// An audit log reason may be attached to the returned request via
+    /// [`AuditLogReason::reason`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ErrorType::Validation`] if the request
+    /// fails validation.
+    ///
+    /// [`AuditLogReason::reason`]: twilight_http::request::AuditLogReason::reason
+    /// [`ErrorType::Validation`]: crate::ErrorType::Validation
+    pub fn remove_timeout(&self, user_id: Id<UserMarker>) -> Result<UpdateGuildMember<'a>, Error> {
+        Ok(self
+            .0
+            .update_guild_member(self.1, user_id)
+            .communication_disabled_until(None)?)
+    }
+
+    /// Search a guild's members by username or nickname prefix.
+    pub const fn search(&self, query: &'a str) -> SearchGuildMembers<'a> {
+        self.0.search_guild_members(self.1, query)
+    }
+
+    /// Search a guild's members using multiple username or nickname
+    /// prefixes, merging the results.
+    ///
+    /// Each prefix is searched for up to the maximum of 1000 members, with up
+    /// to [`DEFAULT_CONCURRENCY`] searches in flight at once. Members are
+    /// returned in the order of the prefixes that matched them, and members
+    /// matching multiple prefixes are only included once, for the first
+    /// prefix that matched them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ErrorType::Request`] if any of the searches
+    /// failed.
+    ///
+    /// [`DEFAULT_CONCURRENCY`]: crate::bulk::DEFAULT_CONCURRENCY
+    /// [`ErrorType::Request`]: crate::ErrorType::Request
+    pub async fn search_prefixes(&self, prefixes: &[&str]) -> Result<Vec<Member>, Error> {
+        // Build the searches before streaming them; holding the mapping
+        // closure across awaits would keep the returned future from being
+        // `Send`.
+        let searches = prefixes
+            .iter()
+            .map(|prefix| async move {
+                let request = self
+                    .0
+                    .search_guild_members(self.1, prefix)
+                    .limit(MEMBER_PAGE_LIMIT)?;
+
+                Ok::<_, Error>(request.exec().await?.models().await?)
+            })
+            .collect::<Vec<_>>();
+
+        let searches = stream::iter(searches)
+            .buffered(DEFAULT_CONCURRENCY)
+            .try_collect::<Vec<_>>()
+            .await?;
+
+        let mut seen = HashSet::new();
+
+        Ok(searches
+            .into_iter()
+            .flatten()
+            .filter(|member| seen.insert(member.user.id))
+            .collect())
+    }
+
+    /// Stream all of a guild's members, retrieving pages as needed.
+    pub fn stream(&self) -> ExecStream<'a, Member> {
+        let client = self.0;
+        let guild_id = self.1;
+
+        paging::user_pages(
+            MEMBER_PAGE_LIMIT,
+            move |after| async move {
+                let mut request = client.guild_members(guild_id).limit(MEMBER_PAGE_LIMIT)?;
+
+                if let Some(user_id) = after {
+                    request = request.after(user_id);
+                }
+
+                Ok::<_, Error>(request.exec().await?.models().await?)
+            },
+            |member| member.user.id,
+        )
+    }
+
+    /// Prevent a guild member from communicating for a duration.
+    ///
+    /// An audit log reason may be attached to the returned request via
+    /// [`AuditLogReason::reason`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ErrorType::Validation`] if the duration is
+    /// longer than 28 days.
+    ///
+    /// [`AuditLogReason::reason`]: twilight_http::request::AuditLogReason::reason
+    /// [`ErrorType::Validation`]: crate::ErrorType::Validation
+    pub fn timeout(
         &self,
-        guild_id: Id<GuildMarker>,
-        query: &'a str,
-    ) -> SearchGuildMembers<'a> {
-        self.0.search_guild_members(guild_id, query)
+        user_id: Id<UserMarker>,
+        duration: Duration,
+    ) -> Result<UpdateGuildMember<'a>, Error> {
+        let until =
+            timestamp::from_system_time(SystemTime::now() + duration.min(TIMEOUT_DURATION_CAP))?;
+
+        Ok(self
+            .0
+            .update_guild_member(self.1, user_id)
+            .communication_disabled_until(Some(until))?)
+    }
+
+    /// Stream a guild's members that are currently timed out, retrieving
+    /// pages as needed.
+    pub fn timed_out(&self) -> ExecStream<'a, Member> {
+        let now = timestamp::as_micros(SystemTime::now());
+
+        Box::pin(self.stream().try_filter(move |member| {
+            future::ready(
+                member
+                    .communication_disabled_until
+                    .is_some_and(|until| until.as_micros() > now),
+            )
+        }))
+    }
+}
+
+/// 1:1 guild member relationships.
+impl<'a> GuildMemberResource<'a> {
+    /// Work with the current user's membership in the guild.
+    pub const fn me(&self) -> GuildMemberMeResource<'a> {
+        GuildMemberMeResource::new(self.0, self.1)
     }
 }
 
@@ -0,0 +1,133 @@
+use crate::Error;
+use http::header::{HeaderName, HeaderValue};
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+use serde::Serialize;
+use std::iter;
+use twilight_http::{
+    client::Client,
+    request::{Method, RequestBuilder},
+    response::marker::EmptyBody,
+    routing::Path,
+};
+use twilight_model::{
+    guild::MfaLevel,
+    id::{marker::GuildMarker, Id},
+};
+use twilight_validate::request::{audit_reason as validate_audit_reason, ValidationError};
+
+/// Name of the header containing an audit log reason.
+const REASON_HEADER_NAME: &str = "x-audit-log-reason";
+
+/// Update the multi-factor authentication level required for a guild's
+/// moderators.
+///
+/// The current user must be the owner of the guild and have multi-factor
+/// authentication enabled on their account.
+///
+/// Created via [`GuildResource::mfa_level`].
+///
+/// [`GuildResource::mfa_level`]: super::GuildResource::mfa_level
+#[derive(Debug)]
+#[must_use = "MFA level updates must be configured and executed"]
+pub struct GuildMfaLevelUpdate<'a> {
+    client: &'a Client,
+    guild_id: Id<GuildMarker>,
+    level: MfaLevel,
+    reason: Option<&'a str>,
+}
+
+impl<'a> GuildMfaLevelUpdate<'a> {
+    pub(super) const fn new(
+        client: &'a Client,
+        guild_id: Id<GuildMarker>,
+        level: MfaLevel,
+    ) -> Self {
+        Self {
+            client,
+            guild_id,
+            level,
+            reason: None,
+        }
+    }
+
+    /// Attach an audit log reason to the update.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`AuditReason`] if the length is invalid.
+    ///
+    /// [`AuditReason`]: twilight_validate::request::ValidationErrorType::AuditReason
+    pub fn reason(mut self, reason: &'a str) -> Result<Self, ValidationError> {
+        validate_audit_reason(reason)?;
+        self.reason = Some(reason);
+
+        Ok(self)
+    }
+
+    /// Execute the update.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ErrorType::Request`] if the request could
+    /// not be built or executed.
+    ///
+    /// [`ErrorType::Request`]: crate::ErrorType::Request
+    pub async fn exec(self) -> Result<(), Error> {
+        let Self {
+            client,
+            guild_id,
+            level,
+            reason,
+        } = self;
+
+        // twilight-http has no route for this endpoint, so the request is built
+        // by hand. It's ratelimited in the guild's bucket, since the guild ID
+        // is its major parameter.
+        let mut builder = RequestBuilder::raw(
+            Method::Post,
+            Path::GuildsId(guild_id.get()),
+            format!("guilds/{guild_id}/mfa"),
+        )
+        .json(&UpdateGuildMfaLevelFields { level })?;
+
+        if let Some(reason) = reason {
+            builder = builder.headers(iter::once(reason_header(reason)?));
+        }
+
+        client.request::<EmptyBody>(builder.build()).await?;
+
+        Ok(())
+    }
+}
+
+/// Body of a request to update a guild's MFA level.
+#[derive(Serialize)]
+struct UpdateGuildMfaLevelFields {
+    level: MfaLevel,
+}
+
+/// Build the header containing an audit log reason, percent-encoded the same
+/// way twilight-http encodes it.
+fn reason_header(reason: &str) -> Result<(HeaderName, HeaderValue), Error> {
+    let encoded = utf8_percent_encode(reason, NON_ALPHANUMERIC).to_string();
+    let value = HeaderValue::from_str(&encoded).map_err(Error::request)?;
+
+    Ok((HeaderName::from_static(REASON_HEADER_NAME), value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{reason_header, GuildMfaLevelUpdate};
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(GuildMfaLevelUpdate<'_>: Debug, Send, Sync);
+
+    #[test]
+    fn reason_headers() {
+        let (name, value) = reason_header("spam bots, again").unwrap();
+
+        assert_eq!("x-audit-log-reason", name.as_str());
+        assert_eq!("spam%20bots%2C%20again", value.to_str().unwrap());
+    }
+}
@@ -8,6 +8,9 @@ mod channel;
 mod emoji;
 mod integration;
 mod invite;
+mod mfa;
+mod ownership;
+mod preset;
 mod preview;
 mod prune;
 mod role;
@@ -17,14 +20,45 @@ mod voice_region;
 mod voice_state;
 mod webhook;
 mod welcome_screen;
+mod wizard;
 
 pub use self::{
-    audit_log::GuildAuditLogResource, ban::GuildBanResource, channel::GuildChannelResource,
-    emoji::GuildEmojiResource, integration::GuildIntegrationResource, invite::GuildInviteResource,
-    member::GuildMemberResource, preview::GuildPreviewResource, prune::GuildPruneRpc,
-    role::GuildRoleResource, template::GuildTemplateResource, vanity_url::GuildVanityUrlResource,
-    voice_region::GuildVoiceRegionResource, voice_state::GuildVoiceStateResource,
-    webhook::GuildWebhookResource, welcome_screen::GuildWelcomeScreenResource,
+    audit_log::{
+        AuditLogEntryContext, GuildAuditLogPoller, GuildAuditLogQuery, GuildAuditLogResource,
+    },
+    ban::{
+        BanConflict, GuildBanBulkCreate, GuildBanBulkDelete, GuildBanImport, GuildBanResource,
+        GuildBanSync, GuildBanSyncReport,
+    },
+    channel::{
+        ChannelOverwriteReport, GuildChannelOverwriteAudit, GuildChannelResource,
+        OverwriteDeviation,
+    },
+    emoji::{
+        EmojiFileRejection, EmojiSyncPlan, EmojiSyncReport, GuildEmojiResource, GuildEmojiSync,
+        RejectedEmojiFile,
+    },
+    integration::{GuildIntegrationGroups, GuildIntegrationResource},
+    invite::GuildInviteResource,
+    member::GuildMemberResource,
+    mfa::GuildMfaLevelUpdate,
+    ownership::GuildOwnershipTransfer,
+    preset::GuildModerationPreset,
+    preview::GuildPreviewResource,
+    prune::{GuildPruneDryRun, GuildPruneReport, GuildPruneRpc, GuildSafePrune},
+    role::{
+        GuildRoleBulkUpdate, GuildRoleClone, GuildRoleResource, GuildRoleTemplateApply,
+        RoleTemplate, RoleTemplateReport,
+    },
+    template::GuildTemplateResource,
+    vanity_url::GuildVanityUrlResource,
+    voice_region::GuildVoiceRegionResource,
+    voice_state::{
+        GuildVoiceStateCurrentUserResource, GuildVoiceStateResource, GuildVoiceStateUserResource,
+    },
+    webhook::{GuildWebhookResource, GuildWebhookRotation, WebhookInventory, WebhookRotation},
+    welcome_screen::GuildWelcomeScreenResource,
+    wizard::{GuildWizard, GuildWizardReport},
 };
 
 use twilight_http::{
@@ -37,9 +71,12 @@ use twilight_http::{
         template::CreateGuildFromTemplate,
     },
 };
-use twilight_model::id::{
-    marker::{GuildMarker, UserMarker},
-    Id,
+use twilight_model::{
+    guild::MfaLevel,
+    id::{
+        marker::{GuildMarker, UserMarker},
+        Id,
+    },
 };
 use twilight_validate::request::ValidationError;
 
@@ -82,6 +119,31 @@ impl<'a> GuildResource<'a> {
 
 /// RPC calls.
 impl<'a> GuildResource<'a> {
+    /// Update the multi-factor authentication level required for a guild's
+    /// moderators.
+    pub const fn mfa_level(
+        &self,
+        guild_id: Id<GuildMarker>,
+        level: MfaLevel,
+    ) -> GuildMfaLevelUpdate<'a> {
+        GuildMfaLevelUpdate::new(self.0, guild_id, level)
+    }
+
+    /// Update a guild's verification level and explicit content filter level
+    /// to match a preset.
+    ///
+    /// An audit log reason can be attached to the returned request.
+    pub const fn moderation_preset(
+        &self,
+        guild_id: Id<GuildMarker>,
+        preset: GuildModerationPreset,
+    ) -> UpdateGuild<'a> {
+        self.0
+            .update_guild(guild_id)
+            .explicit_content_filter(Some(preset.explicit_content_filter()))
+            .verification_level(Some(preset.verification_level()))
+    }
+
     /// Work with a guild's prune capability.
     pub const fn prune(&self, guild_id: Id<GuildMarker>) -> GuildPruneRpc<'a> {
         GuildPruneRpc::new(self.0, guild_id)
@@ -101,6 +163,23 @@ impl<'a> GuildResource<'a> {
     ) -> Result<CreateGuildFromTemplate<'_>, ValidationError> {
         self.0.create_guild_from_template(template_code, name)
     }
+
+    /// Transfer ownership of a guild to another member.
+    ///
+    /// The transfer must be confirmed before a request is produced.
+    pub const fn transfer_ownership(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+    ) -> GuildOwnershipTransfer<'a> {
+        GuildOwnershipTransfer::new(self.0, guild_id, user_id)
+    }
+
+    /// Create a guild with categories, channels, roles, and permission
+    /// overwrites declared by name.
+    pub const fn wizard(&self, name: &'a str) -> GuildWizard<'a> {
+        GuildWizard::new(self.0, name)
+    }
 }
 
 /// 1:1 guild relationships.
@@ -172,12 +251,8 @@ impl<'a> GuildResource<'a> {
     }
 
     /// Work with a guild's voice states.
-    pub const fn voice_states(
-        &self,
-        guild_id: Id<GuildMarker>,
-        user_id: Id<UserMarker>,
-    ) -> GuildVoiceStateResource<'a> {
-        GuildVoiceStateResource::new(self.0, guild_id, user_id)
+    pub const fn voice_states(&self, guild_id: Id<GuildMarker>) -> GuildVoiceStateResource<'a> {
+        GuildVoiceStateResource::new(self.0, guild_id)
     }
 
     /// Work with a guild's webhooks.
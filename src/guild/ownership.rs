@@ -0,0 +1,61 @@
+use twilight_http::{client::Client, request::guild::UpdateGuild};
+use twilight_model::id::{
+    marker::{GuildMarker, UserMarker},
+    Id,
+};
+
+/// Pending transfer of a guild's ownership to another member.
+///
+/// Transferring ownership can't be undone by the current user, so the
+/// transfer must be explicitly confirmed via [`confirm`] before a request is
+/// produced.
+///
+/// Created via [`GuildResource::transfer_ownership`].
+///
+/// [`GuildResource::transfer_ownership`]: super::GuildResource::transfer_ownership
+/// [`confirm`]: Self::confirm
+#[derive(Debug)]
+#[must_use = "ownership transfers must be confirmed and executed"]
+pub struct GuildOwnershipTransfer<'a> {
+    client: &'a Client,
+    guild_id: Id<GuildMarker>,
+    user_id: Id<UserMarker>,
+}
+
+impl<'a> GuildOwnershipTransfer<'a> {
+    pub(super) const fn new(
+        client: &'a Client,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+    ) -> Self {
+        Self {
+            client,
+            guild_id,
+            user_id,
+        }
+    }
+
+    /// ID of the user that would become the owner of the guild.
+    pub const fn user_id(&self) -> Id<UserMarker> {
+        self.user_id
+    }
+
+    /// Confirm the transfer, returning a request that makes the user the
+    /// owner of the guild.
+    ///
+    /// An audit log reason can be attached to the returned request.
+    pub const fn confirm(self) -> UpdateGuild<'a> {
+        self.client
+            .update_guild(self.guild_id)
+            .owner_id(self.user_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GuildOwnershipTransfer;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(GuildOwnershipTransfer<'_>: Debug, Send, Sync);
+}
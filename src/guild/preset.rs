@@ -0,0 +1,53 @@
+use twilight_model::guild::{ExplicitContentFilter, VerificationLevel};
+
+/// Named combination of a guild's verification level and explicit content
+/// filter level.
+///
+/// Applied via [`GuildResource::moderation_preset`].
+///
+/// [`GuildResource::moderation_preset`]: super::GuildResource::moderation_preset
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum GuildModerationPreset {
+    /// No verification and no scanning of media content.
+    Relaxed,
+    /// Members must have a verified email, and media content from members
+    /// without roles is scanned.
+    Standard,
+    /// Members must have been members of the guild for 10 minutes, and all
+    /// media content is scanned.
+    Strict,
+    /// Members must have a verified phone number, and all media content is
+    /// scanned.
+    Lockdown,
+}
+
+impl GuildModerationPreset {
+    /// Explicit content filter level of the preset.
+    pub const fn explicit_content_filter(self) -> ExplicitContentFilter {
+        match self {
+            Self::Relaxed => ExplicitContentFilter::None,
+            Self::Standard => ExplicitContentFilter::MembersWithoutRole,
+            Self::Strict | Self::Lockdown => ExplicitContentFilter::AllMembers,
+        }
+    }
+
+    /// Verification level of the preset.
+    pub const fn verification_level(self) -> VerificationLevel {
+        match self {
+            Self::Relaxed => VerificationLevel::None,
+            Self::Standard => VerificationLevel::Low,
+            Self::Strict => VerificationLevel::High,
+            Self::Lockdown => VerificationLevel::VeryHigh,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GuildModerationPreset;
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_impl_all!(GuildModerationPreset: Clone, Copy, Debug, Eq, Hash, PartialEq, Send, Sync);
+}
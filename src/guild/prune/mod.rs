@@ -1,3 +1,7 @@
+mod safe;
+
+pub use self::safe::{GuildPruneDryRun, GuildPruneReport, GuildSafePrune};
+
 use twilight_http::{
     client::Client,
     request::guild::{CreateGuildPrune, GetGuildPruneCount},
@@ -23,6 +27,12 @@ impl<'a> GuildPruneRpc<'a> {
     pub const fn post(&self) -> CreateGuildPrune<'a> {
         self.0.create_guild_prune(self.1)
     }
+
+    /// Begin a guild prune after checking how many members would be removed,
+    /// refusing to prune more than a threshold.
+    pub const fn safe(&self, threshold: u64) -> GuildSafePrune<'a> {
+        GuildSafePrune::new(self.0, self.1, threshold)
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,262 @@
+use crate::{guild::member::GuildMemberResource, timestamp, Error, ErrorType};
+use futures_util::{future, TryStreamExt};
+use std::time::{Duration, SystemTime};
+use twilight_http::{client::Client, request::AuditLogReason};
+use twilight_model::{
+    guild::Member,
+    id::{
+        marker::{GuildMarker, RoleMarker},
+        Id,
+    },
+};
+use twilight_validate::request::{
+    audit_reason as validate_audit_reason, guild_prune_days as validate_guild_prune_days,
+    ValidationError,
+};
+
+/// Number of days of inactivity Discord uses if none is specified.
+const DEFAULT_DAYS: u16 = 7;
+
+/// Members that a prune would remove, without removing them.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct GuildPruneDryRun {
+    /// Members whose roles and join date make them eligible to be pruned.
+    ///
+    /// Discord doesn't expose when members were last active, so this may
+    /// include members that have been active recently and won't be pruned.
+    pub candidates: Vec<Member>,
+    /// Number of members Discord reported would be pruned.
+    pub count: u64,
+    /// Number of days of inactivity after which members are pruned.
+    pub days: u16,
+}
+
+/// Outcome of a guild prune.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct GuildPruneReport {
+    /// Number of days of inactivity after which members were pruned.
+    pub days: u16,
+    /// Number of members Discord reported would be pruned before the prune
+    /// began.
+    pub estimated: u64,
+    /// Number of members that were pruned.
+    ///
+    /// This is only present if the prune count was computed.
+    pub pruned: Option<u64>,
+}
+
+/// Begin a guild prune after checking how many members would be removed.
+///
+/// The prune count is first retrieved with the same number of days and
+/// included roles, and the prune is refused if it would remove more members
+/// than the threshold.
+///
+/// Created via [`GuildPruneRpc::safe`].
+///
+/// [`GuildPruneRpc::safe`]: super::GuildPruneRpc::safe
+#[derive(Debug)]
+#[must_use = "safe prunes must be configured and executed"]
+pub struct GuildSafePrune<'a> {
+    client: &'a Client,
+    compute_prune_count: bool,
+    days: u16,
+    guild_id: Id<GuildMarker>,
+    include_roles: &'a [Id<RoleMarker>],
+    reason: Option<&'a str>,
+    threshold: u64,
+}
+
+impl<'a> GuildSafePrune<'a> {
+    pub(super) const fn new(client: &'a Client, guild_id: Id<GuildMarker>, threshold: u64) -> Self {
+        Self {
+            client,
+            compute_prune_count: true,
+            days: DEFAULT_DAYS,
+            guild_id,
+            include_roles: &[],
+            reason: None,
+            threshold,
+        }
+    }
+
+    /// Set whether to return the number of pruned members.
+    ///
+    /// Discord discourages computing the count for large guilds. Defaults to
+    /// true.
+    pub const fn compute_prune_count(mut self, compute_prune_count: bool) -> Self {
+        self.compute_prune_count = compute_prune_count;
+
+        self
+    }
+
+    /// Set the number of days that a member must be inactive before being
+    /// pruned.
+    ///
+    /// Defaults to 7.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`GuildPruneDays`] if the number of days is 0
+    /// or more than 30.
+    ///
+    /// [`GuildPruneDays`]: twilight_validate::request::ValidationErrorType::GuildPruneDays
+    pub fn days(mut self, days: u16) -> Result<Self, ValidationError> {
+        validate_guild_prune_days(days)?;
+        self.days = days;
+
+        Ok(self)
+    }
+
+    /// Set the roles whose members are included in the prune.
+    ///
+    /// By default members with any roles are not pruned.
+    pub const fn include_roles(mut self, include_roles: &'a [Id<RoleMarker>]) -> Self {
+        self.include_roles = include_roles;
+
+        self
+    }
+
+    /// Attach an audit log reason to the prune.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`AuditReason`] if the length is invalid.
+    ///
+    /// [`AuditReason`]: twilight_validate::request::ValidationErrorType::AuditReason
+    pub fn reason(mut self, reason: &'a str) -> Result<Self, ValidationError> {
+        validate_audit_reason(reason)?;
+        self.reason = Some(reason);
+
+        Ok(self)
+    }
+
+    /// List the members the prune would remove without removing them.
+    ///
+    /// Candidates are found by paging through the guild's members. The
+    /// threshold is not checked.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ErrorType::Request`] if the prune count or
+    /// a page of members could not be retrieved.
+    ///
+    /// [`ErrorType::Request`]: crate::ErrorType::Request
+    pub async fn dry_run(self) -> Result<GuildPruneDryRun, Error> {
+        let count = self.count().await?;
+
+        // Members that joined within the period have been active within it.
+        let cutoff = timestamp::as_micros(
+            SystemTime::now() - Duration::from_secs(u64::from(self.days) * 24 * 60 * 60),
+        );
+        let include_roles = self.include_roles;
+
+        let candidates = GuildMemberResource::new(self.client, self.guild_id)
+            .stream()
+            .try_filter(|member| {
+                future::ready(
+                    member.joined_at.as_micros() < cutoff
+                        && member
+                            .roles
+                            .iter()
+                            .all(|role_id| include_roles.contains(role_id)),
+                )
+            })
+            .try_collect()
+            .await?;
+
+        Ok(GuildPruneDryRun {
+            candidates,
+            count,
+            days: self.days,
+        })
+    }
+
+    /// Execute the prune if it wouldn't remove more members than the
+    /// threshold.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ErrorType::PruneThresholdExceeded`] if the
+    /// prune would remove more members than the threshold.
+    ///
+    /// Returns an error of type [`ErrorType::Request`] if the prune count
+    /// could not be retrieved or the prune could not be executed.
+    ///
+    /// [`ErrorType::PruneThresholdExceeded`]: crate::ErrorType::PruneThresholdExceeded
+    /// [`ErrorType::Request`]: crate::ErrorType::Request
+    pub async fn exec(self) -> Result<GuildPruneReport, Error> {
+        let estimated = self.count().await?;
+
+        let Self {
+            client,
+            compute_prune_count,
+            days,
+            guild_id,
+            include_roles,
+            reason,
+            threshold,
+        } = self;
+
+        if estimated > threshold {
+            return Err(Error {
+                kind: ErrorType::PruneThresholdExceeded {
+                    count: estimated,
+                    threshold,
+                },
+                source: None,
+            });
+        }
+
+        let mut request = client
+            .create_guild_prune(guild_id)
+            .days(days)?
+            .include_roles(include_roles)
+            .compute_prune_count(compute_prune_count);
+
+        if let Some(reason) = reason {
+            request = request.reason(reason)?;
+        }
+
+        let response = request.exec().await?;
+
+        // Discord doesn't return a count unless it was computed.
+        let pruned = if compute_prune_count {
+            Some(response.model().await?.pruned)
+        } else {
+            None
+        };
+
+        Ok(GuildPruneReport {
+            days,
+            estimated,
+            pruned,
+        })
+    }
+
+    /// Retrieve the number of members the prune would remove.
+    async fn count(&self) -> Result<u64, Error> {
+        Ok(self
+            .client
+            .guild_prune_count(self.guild_id)
+            .days(self.days)?
+            .include_roles(self.include_roles)
+            .exec()
+            .await?
+            .model()
+            .await?
+            .pruned)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GuildPruneDryRun, GuildPruneReport, GuildSafePrune};
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(GuildPruneDryRun: Clone, Debug, Eq, Send, Sync);
+    assert_impl_all!(GuildPruneReport: Clone, Debug, Eq, Send, Sync);
+    assert_impl_all!(GuildSafePrune<'_>: Debug, Send, Sync);
+}
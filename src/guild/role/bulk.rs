@@ -0,0 +1,129 @@
+use crate::{
+    bulk::{BulkOptions, BulkOutcome},
+    Error,
+};
+use twilight_http::{client::Client, request::AuditLogReason};
+use twilight_model::id::{
+    marker::{GuildMarker, RoleMarker, UserMarker},
+    Id,
+};
+
+/// Whether a bulk update adds or removes the role.
+#[derive(Clone, Copy, Debug)]
+enum Kind {
+    Add,
+    Remove,
+}
+
+/// Add or remove a role from many guild members at once.
+///
+/// Created via [`GuildRoleResource::assign_to`] or
+/// [`GuildRoleResource::remove_from`].
+///
+/// [`GuildRoleResource::assign_to`]: super::GuildRoleResource::assign_to
+/// [`GuildRoleResource::remove_from`]: super::GuildRoleResource::remove_from
+#[derive(Debug)]
+#[must_use = "bulk operations must be configured and executed"]
+pub struct GuildRoleBulkUpdate<'a> {
+    client: &'a Client,
+    guild_id: Id<GuildMarker>,
+    kind: Kind,
+    options: BulkOptions<'a, Id<UserMarker>>,
+    role_id: Id<RoleMarker>,
+    user_ids: &'a [Id<UserMarker>],
+}
+
+impl<'a> GuildRoleBulkUpdate<'a> {
+    pub(super) const fn add(
+        client: &'a Client,
+        guild_id: Id<GuildMarker>,
+        role_id: Id<RoleMarker>,
+        user_ids: &'a [Id<UserMarker>],
+    ) -> Self {
+        Self::new(client, guild_id, Kind::Add, role_id, user_ids)
+    }
+
+    pub(super) const fn remove(
+        client: &'a Client,
+        guild_id: Id<GuildMarker>,
+        role_id: Id<RoleMarker>,
+        user_ids: &'a [Id<UserMarker>],
+    ) -> Self {
+        Self::new(client, guild_id, Kind::Remove, role_id, user_ids)
+    }
+
+    const fn new(
+        client: &'a Client,
+        guild_id: Id<GuildMarker>,
+        kind: Kind,
+        role_id: Id<RoleMarker>,
+        user_ids: &'a [Id<UserMarker>],
+    ) -> Self {
+        Self {
+            client,
+            guild_id,
+            kind,
+            options: BulkOptions::new(),
+            role_id,
+            user_ids,
+        }
+    }
+
+    /// Set the options of the operation.
+    pub fn options(mut self, options: BulkOptions<'a, Id<UserMarker>>) -> Self {
+        self.options = options;
+
+        self
+    }
+
+    /// Execute the operation, returning the outcome for each user.
+    pub async fn exec(self) -> BulkOutcome<Id<UserMarker>> {
+        let Self {
+            client,
+            guild_id,
+            kind,
+            options,
+            role_id,
+            user_ids,
+        } = self;
+
+        let reason = options.reason;
+
+        options
+            .run(user_ids, |user_id| async move {
+                match kind {
+                    Kind::Add => {
+                        let mut request = client.add_guild_member_role(guild_id, user_id, role_id);
+
+                        if let Some(reason) = reason {
+                            request = request.reason(reason)?;
+                        }
+
+                        request.exec().await?;
+                    }
+                    Kind::Remove => {
+                        let mut request =
+                            client.remove_guild_member_role(guild_id, user_id, role_id);
+
+                        if let Some(reason) = reason {
+                            request = request.reason(reason)?;
+                        }
+
+                        request.exec().await?;
+                    }
+                }
+
+                Ok::<_, Error>(())
+            })
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GuildRoleBulkUpdate;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(GuildRoleBulkUpdate<'_>: Debug, Send, Sync);
+}
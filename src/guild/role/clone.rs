@@ -0,0 +1,169 @@
+use crate::{image, Error, ErrorType};
+use twilight_http::{client::Client, request::AuditLogReason};
+use twilight_model::{
+    guild::Role,
+    id::{
+        marker::{GuildMarker, RoleMarker},
+        Id,
+    },
+};
+use twilight_validate::request::{audit_reason as validate_audit_reason, ValidationError};
+
+/// Create a copy of a role, either in the same guild or in another guild.
+///
+/// The role's name, permissions, color, hoist, mentionability, and icon are
+/// copied. Unless an icon is provided via [`icon`], the role's image icon is
+/// downloaded from Discord's CDN, which requires the `cdn` feature.
+///
+/// Created via [`GuildRoleResource::clone_role`].
+///
+/// [`GuildRoleResource::clone_role`]: super::GuildRoleResource::clone_role
+/// [`icon`]: Self::icon
+#[derive(Debug)]
+#[must_use = "role clones must be configured and executed"]
+pub struct GuildRoleClone<'a> {
+    client: &'a Client,
+    guild_id: Id<GuildMarker>,
+    icon: Option<&'a [u8]>,
+    name: Option<&'a str>,
+    reason: Option<&'a str>,
+    role_id: Id<RoleMarker>,
+    target_guild_id: Option<Id<GuildMarker>>,
+}
+
+impl<'a> GuildRoleClone<'a> {
+    pub(super) const fn new(
+        client: &'a Client,
+        guild_id: Id<GuildMarker>,
+        role_id: Id<RoleMarker>,
+    ) -> Self {
+        Self {
+            client,
+            guild_id,
+            icon: None,
+            name: None,
+            reason: None,
+            role_id,
+            target_guild_id: None,
+        }
+    }
+
+    /// Set the image icon of the copy instead of copying the role's icon.
+    ///
+    /// This must be a Data URI, in the form of
+    /// `data:image/{type};base64,{data}`.
+    pub const fn icon(mut self, icon: &'a [u8]) -> Self {
+        self.icon = Some(icon);
+
+        self
+    }
+
+    /// Create the copy in another guild.
+    pub const fn into_guild(mut self, guild_id: Id<GuildMarker>) -> Self {
+        self.target_guild_id = Some(guild_id);
+
+        self
+    }
+
+    /// Set the name of the copy instead of using the role's name.
+    pub const fn name(mut self, name: &'a str) -> Self {
+        self.name = Some(name);
+
+        self
+    }
+
+    /// Attach an audit log reason to the creation of the copy.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`AuditReason`] if the length is invalid.
+    ///
+    /// [`AuditReason`]: twilight_validate::request::ValidationErrorType::AuditReason
+    pub fn reason(mut self, reason: &'a str) -> Result<Self, ValidationError> {
+        validate_audit_reason(reason)?;
+        self.reason = Some(reason);
+
+        Ok(self)
+    }
+
+    /// Execute the clone, returning the created role.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ErrorType::ImageUnavailable`] if the role
+    /// has an image icon, no icon was provided, and the `cdn` feature is
+    /// disabled.
+    ///
+    /// Returns an error of type [`ErrorType::Request`] if the guild's roles
+    /// or the role's icon could not be retrieved, or the copy could not be
+    /// created.
+    ///
+    /// Returns an error of type [`ErrorType::UnknownRole`] if the role does
+    /// not exist in the guild.
+    ///
+    /// [`ErrorType::ImageUnavailable`]: crate::ErrorType::ImageUnavailable
+    /// [`ErrorType::Request`]: crate::ErrorType::Request
+    /// [`ErrorType::UnknownRole`]: crate::ErrorType::UnknownRole
+    pub async fn exec(self) -> Result<Role, Error> {
+        let Self {
+            client,
+            guild_id,
+            icon,
+            name,
+            reason,
+            role_id,
+            target_guild_id,
+        } = self;
+
+        let roles = client.roles(guild_id).exec().await?.models().await?;
+        let role = roles.iter().find(|role| role.id == role_id).ok_or(Error {
+            kind: ErrorType::UnknownRole { role_id },
+            source: None,
+        })?;
+
+        let role_icon = match (icon, role.icon) {
+            (None, Some(hash)) => {
+                let extension = image::extension(hash);
+                let image =
+                    image::fetch(&format!("role-icons/{role_id}/{hash}.{extension}")).await?;
+
+                Some(image::data_uri(
+                    image::mime_type(&image).unwrap_or("image/png"),
+                    &image,
+                ))
+            }
+            _ => None,
+        };
+
+        let mut request = client
+            .create_role(target_guild_id.unwrap_or(guild_id))
+            .name(name.unwrap_or(&role.name))
+            .color(role.color)
+            .hoist(role.hoist)
+            .mentionable(role.mentionable)
+            .permissions(role.permissions);
+
+        if let Some(icon) = icon.or(role_icon.as_deref()) {
+            request = request.icon(icon);
+        }
+
+        if let Some(emoji) = &role.unicode_emoji {
+            request = request.unicode_emoji(emoji);
+        }
+
+        if let Some(reason) = reason {
+            request = request.reason(reason)?;
+        }
+
+        Ok(request.exec().await?.model().await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GuildRoleClone;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(GuildRoleClone<'_>: Debug, Send, Sync);
+}
@@ -0,0 +1,181 @@
+mod bulk;
+mod clone;
+mod position;
+mod template;
+
+pub use self::{
+    bulk::GuildRoleBulkUpdate,
+    clone::GuildRoleClone,
+    template::{GuildRoleTemplateApply, RoleTemplate, RoleTemplateReport},
+};
+
+use crate::Error;
+use twilight_http::{
+    client::Client,
+    request::guild::role::{
+        CreateRole, DeleteRole, GetGuildRoles, UpdateRole, UpdateRolePositions,
+    },
+};
+use twilight_model::{
+    guild::Role,
+    id::{
+        marker::{GuildMarker, RoleMarker, UserMarker},
+        Id,
+    },
+};
+
+/// Work with a guild's roles.
+#[derive(Clone, Debug)]
+pub struct GuildRoleResource<'a>(&'a Client, Id<GuildMarker>);
+
+impl<'a> GuildRoleResource<'a> {
+    /// Create a resource instance to work with a guild's roles.
+    pub const fn new(client: &'a Client, guild_id: Id<GuildMarker>) -> Self {
+        Self(client, guild_id)
+    }
+
+    /// Delete a guild's role.
+    pub const fn delete(&self, role_id: Id<RoleMarker>) -> DeleteRole<'a> {
+        self.0.delete_role(self.1, role_id)
+    }
+
+    /// Get a guild's role, if there is one.
+    ///
+    /// The role is retrieved from the guild's list of roles.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ErrorType::Request`] if the guild's roles
+    /// could not be retrieved.
+    ///
+    /// [`ErrorType::Request`]: crate::ErrorType::Request
+    pub async fn get(&self, role_id: Id<RoleMarker>) -> Result<Option<Role>, Error> {
+        let roles = self.0.roles(self.1).exec().await?.models().await?;
+
+        Ok(roles.into_iter().find(|role| role.id == role_id))
+    }
+
+    /// List a guild's roles.
+    pub const fn list(&self) -> GetGuildRoles<'a> {
+        self.0.roles(self.1)
+    }
+
+    /// Update a guild's role.
+    pub const fn patch(&self, role_id: Id<RoleMarker>) -> UpdateRole<'a> {
+        self.0.update_role(self.1, role_id)
+    }
+
+    /// Update the positions of a guild's roles.
+    pub const fn patch_positions(
+        &self,
+        positions: &'a [(Id<RoleMarker>, u64)],
+    ) -> UpdateRolePositions<'a> {
+        self.0.update_role_positions(self.1, positions)
+    }
+
+    /// Create a guild role.
+    pub const fn post(&self) -> CreateRole<'a> {
+        self.0.create_role(self.1)
+    }
+}
+
+/// RPC calls.
+impl<'a> GuildRoleResource<'a> {
+    /// Create or update a guild's roles to match templates, matching roles
+    /// to templates by name.
+    ///
+    /// Refer to [`GuildRoleTemplateApply`] for how templates are applied.
+    pub const fn apply_templates(
+        &self,
+        templates: &'a [RoleTemplate<'a>],
+    ) -> GuildRoleTemplateApply<'a> {
+        GuildRoleTemplateApply::new(self.0, self.1, templates)
+    }
+
+    /// Add a role to many guild members at once.
+    ///
+    /// Requests are executed concurrently; refer to [`GuildRoleBulkUpdate`]
+    /// for configuring the operation.
+    pub const fn assign_to(
+        &self,
+        role_id: Id<RoleMarker>,
+        user_ids: &'a [Id<UserMarker>],
+    ) -> GuildRoleBulkUpdate<'a> {
+        GuildRoleBulkUpdate::add(self.0, self.1, role_id, user_ids)
+    }
+
+    /// Create a copy of a role in the guild or in another guild.
+    ///
+    /// Refer to [`GuildRoleClone`] for what is copied.
+    pub const fn clone_role(&self, role_id: Id<RoleMarker>) -> GuildRoleClone<'a> {
+        GuildRoleClone::new(self.0, self.1, role_id)
+    }
+
+    /// Move a role directly above another role, returning the guild's roles.
+    ///
+    /// Only roles whose positions change are updated. Moving a role above
+    /// itself doesn't update any roles.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ErrorType::RoleHierarchy`] if the role is
+    /// not, or would not be, below the current user's highest role. Guild
+    /// owners can move any role.
+    ///
+    /// Returns an error of type [`ErrorType::Request`] if the guild, its
+    /// roles, or the current member could not be retrieved, or the positions
+    /// could not be updated.
+    ///
+    /// Returns an error of type [`ErrorType::UnknownRole`] if either role does
+    /// not exist in the guild.
+    ///
+    /// [`ErrorType::Request`]: crate::ErrorType::Request
+    /// [`ErrorType::RoleHierarchy`]: crate::ErrorType::RoleHierarchy
+    /// [`ErrorType::UnknownRole`]: crate::ErrorType::UnknownRole
+    pub async fn move_above(
+        &self,
+        role_id: Id<RoleMarker>,
+        target_id: Id<RoleMarker>,
+    ) -> Result<Vec<Role>, Error> {
+        position::move_role(self.0, self.1, role_id, target_id, true).await
+    }
+
+    /// Move a role directly below another role, returning the guild's roles.
+    ///
+    /// Only roles whose positions change are updated. Moving a role below
+    /// itself doesn't update any roles.
+    ///
+    /// # Errors
+    ///
+    /// Refer to [`move_above`] for error information.
+    ///
+    /// [`move_above`]: Self::move_above
+    pub async fn move_below(
+        &self,
+        role_id: Id<RoleMarker>,
+        target_id: Id<RoleMarker>,
+    ) -> Result<Vec<Role>, Error> {
+        position::move_role(self.0, self.1, role_id, target_id, false).await
+    }
+
+    /// Remove a role from many guild members at once.
+    ///
+    /// Requests are executed concurrently; refer to [`GuildRoleBulkUpdate`]
+    /// for configuring the operation.
+    pub const fn remove_from(
+        &self,
+        role_id: Id<RoleMarker>,
+        user_ids: &'a [Id<UserMarker>],
+    ) -> GuildRoleBulkUpdate<'a> {
+        GuildRoleBulkUpdate::remove(self.0, self.1, role_id, user_ids)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GuildRoleResource;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(GuildRoleResource<'_>: Clone, Debug, Send, Sync);
+}
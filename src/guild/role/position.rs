@@ -0,0 +1,181 @@
+use crate::{Error, ErrorType};
+use std::convert::TryFrom;
+use twilight_http::client::Client;
+use twilight_model::{
+    guild::Role,
+    id::{
+        marker::{GuildMarker, RoleMarker},
+        Id,
+    },
+};
+
+/// Move a role directly above or below another role, updating only the roles
+/// whose positions change.
+pub(super) async fn move_role(
+    client: &Client,
+    guild_id: Id<GuildMarker>,
+    role_id: Id<RoleMarker>,
+    target_id: Id<RoleMarker>,
+    above: bool,
+) -> Result<Vec<Role>, Error> {
+    let mut roles = client.roles(guild_id).exec().await?.models().await?;
+    roles.sort();
+
+    // The @everyone role is always at the bottom and can't be moved.
+    let current = roles
+        .iter()
+        .filter(|role| role.id != guild_id.cast())
+        .map(|role| (role.id, role.position))
+        .collect::<Vec<_>>();
+
+    let order = reorder(&current, role_id, target_id, above)?;
+
+    // Moving a role relative to itself leaves every role where it is.
+    if role_id == target_id {
+        return Ok(roles);
+    }
+
+    let current_user_id = client.current_user().exec().await?.model().await?.id;
+    let owner_id = client.guild(guild_id).exec().await?.model().await?.owner_id;
+
+    // Guild owners can move any role, regardless of their own roles.
+    if current_user_id != owner_id {
+        let member = client
+            .guild_member(guild_id, current_user_id)
+            .exec()
+            .await?
+            .model()
+            .await?;
+
+        let highest = roles
+            .iter()
+            .rev()
+            .find(|role| member.roles.contains(&role.id))
+            .map(|role| role.id);
+
+        let is_below_highest = |order: &[Id<RoleMarker>]| {
+            let index = |id| order.iter().position(|&ordered| ordered == id);
+
+            highest.is_some_and(|highest| index(role_id) < index(highest))
+        };
+
+        let current_order = current.iter().map(|(id, _)| *id).collect::<Vec<_>>();
+
+        if !is_below_highest(&current_order) || !is_below_highest(&order) {
+            return Err(Error {
+                kind: ErrorType::RoleHierarchy { role_id },
+                source: None,
+            });
+        }
+    }
+
+    let updates = updates(&current, &order);
+
+    if updates.is_empty() {
+        return Ok(roles);
+    }
+
+    Ok(client
+        .update_role_positions(guild_id, &updates)
+        .exec()
+        .await?
+        .models()
+        .await?)
+}
+
+/// Order of roles, from lowest to highest, after moving a role directly above
+/// or below a target.
+///
+/// Roles must be provided from lowest to highest. Moving a role relative to
+/// itself leaves the order unchanged.
+fn reorder(
+    roles: &[(Id<RoleMarker>, i64)],
+    role_id: Id<RoleMarker>,
+    target_id: Id<RoleMarker>,
+    above: bool,
+) -> Result<Vec<Id<RoleMarker>>, Error> {
+    let unknown = |role_id| Error {
+        kind: ErrorType::UnknownRole { role_id },
+        source: None,
+    };
+
+    if !roles.iter().any(|(id, _)| *id == role_id) {
+        return Err(unknown(role_id));
+    }
+
+    if role_id == target_id {
+        return Ok(roles.iter().map(|(id, _)| *id).collect());
+    }
+
+    let mut order = roles
+        .iter()
+        .map(|(id, _)| *id)
+        .filter(|id| *id != role_id)
+        .collect::<Vec<_>>();
+
+    let target = order
+        .iter()
+        .position(|id| *id == target_id)
+        .ok_or_else(|| unknown(target_id))?;
+
+    order.insert(if above { target + 1 } else { target }, role_id);
+
+    Ok(order)
+}
+
+/// Position updates needed to go from the current positions of roles to an
+/// order, where the lowest role is at position 1.
+fn updates(
+    current: &[(Id<RoleMarker>, i64)],
+    order: &[Id<RoleMarker>],
+) -> Vec<(Id<RoleMarker>, u64)> {
+    let mut updates = Vec::new();
+
+    for (position, role_id) in (1..).zip(order) {
+        let unchanged = current
+            .iter()
+            .any(|(id, current)| id == role_id && i64::try_from(position).ok() == Some(*current));
+
+        if !unchanged {
+            updates.push((*role_id, position));
+        }
+    }
+
+    updates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{reorder, updates};
+    use crate::Error;
+    use twilight_model::id::Id;
+
+    #[test]
+    fn minimal_updates() -> Result<(), Error> {
+        let roles = [
+            (Id::new(1), 1),
+            (Id::new(2), 2),
+            (Id::new(3), 3),
+            (Id::new(4), 4),
+        ];
+
+        let order = reorder(&roles, Id::new(1), Id::new(3), true)?;
+        assert_eq!(vec![Id::new(2), Id::new(3), Id::new(1), Id::new(4)], order);
+        assert_eq!(
+            vec![(Id::new(2), 1), (Id::new(3), 2), (Id::new(1), 3)],
+            updates(&roles, &order)
+        );
+
+        let order = reorder(&roles, Id::new(4), Id::new(4), false)?;
+        assert_eq!(vec![Id::new(1), Id::new(2), Id::new(3), Id::new(4)], order);
+        assert!(updates(&roles, &order).is_empty());
+
+        let order = reorder(&roles, Id::new(5), Id::new(5), true);
+        assert!(order.is_err());
+
+        let order = reorder(&roles, Id::new(2), Id::new(3), false)?;
+        assert!(updates(&roles, &order).is_empty());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,290 @@
+use crate::Error;
+use twilight_http::{
+    client::Client,
+    request::{guild::create_guild::RoleFields, AuditLogReason},
+};
+use twilight_model::{
+    guild::{Permissions, Role},
+    id::{
+        marker::{GuildMarker, RoleMarker},
+        Id,
+    },
+};
+use twilight_validate::request::{audit_reason as validate_audit_reason, ValidationError};
+
+/// Description of a role to apply to guilds.
+///
+/// Roles are matched to templates by name.
+///
+/// Refer to [`GuildRoleResource::apply_templates`] for how templates are
+/// applied.
+///
+/// [`GuildRoleResource::apply_templates`]: super::GuildRoleResource::apply_templates
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[must_use = "role templates have no effect unless applied"]
+pub struct RoleTemplate<'a> {
+    color: u32,
+    hoist: bool,
+    mentionable: bool,
+    name: &'a str,
+    permissions: Permissions,
+    unicode_emoji: Option<&'a str>,
+}
+
+impl<'a> RoleTemplate<'a> {
+    /// Create a template for a role with a name and permissions.
+    ///
+    /// The role has no color, is not hoisted, and is not mentionable unless
+    /// configured otherwise.
+    pub const fn new(name: &'a str, permissions: Permissions) -> Self {
+        Self {
+            color: 0,
+            hoist: false,
+            mentionable: false,
+            name,
+            permissions,
+            unicode_emoji: None,
+        }
+    }
+
+    /// Create a template from an existing role.
+    pub fn from_role(role: &'a Role) -> Self {
+        Self {
+            color: role.color,
+            hoist: role.hoist,
+            mentionable: role.mentionable,
+            name: &role.name,
+            permissions: role.permissions,
+            unicode_emoji: role.unicode_emoji.as_deref(),
+        }
+    }
+
+    /// Set the color of the role.
+    pub const fn color(mut self, color: u32) -> Self {
+        self.color = color;
+
+        self
+    }
+
+    /// Set whether the role is displayed separately in the member list.
+    pub const fn hoist(mut self, hoist: bool) -> Self {
+        self.hoist = hoist;
+
+        self
+    }
+
+    /// Set whether the role can be mentioned by everyone.
+    pub const fn mentionable(mut self, mentionable: bool) -> Self {
+        self.mentionable = mentionable;
+
+        self
+    }
+
+    /// Set the unicode emoji icon of the role.
+    ///
+    /// The guild must have the `ROLE_ICONS` feature.
+    pub const fn unicode_emoji(mut self, unicode_emoji: &'a str) -> Self {
+        self.unicode_emoji = Some(unicode_emoji);
+
+        self
+    }
+
+    /// Name of the role.
+    pub const fn name(&self) -> &'a str {
+        self.name
+    }
+
+    /// Whether a role already matches the template.
+    ///
+    /// The unicode emoji icon is only compared if the template has one.
+    pub fn matches(&self, role: &Role) -> bool {
+        role.name == self.name
+            && role.color == self.color
+            && role.hoist == self.hoist
+            && role.mentionable == self.mentionable
+            && role.permissions == self.permissions
+            && (self.unicode_emoji.is_none() || role.unicode_emoji.as_deref() == self.unicode_emoji)
+    }
+
+    /// Fields of a role to create alongside a guild, identified by a
+    /// placeholder ID.
+    ///
+    /// The unicode emoji icon is not included.
+    pub(crate) fn fields(&self, id: Id<RoleMarker>) -> RoleFields {
+        RoleFields {
+            color: Some(self.color),
+            hoist: Some(self.hoist),
+            id,
+            mentionable: Some(self.mentionable),
+            name: self.name.to_owned(),
+            permissions: Some(self.permissions),
+            position: None,
+        }
+    }
+}
+
+/// Roles created, updated, and left unchanged when applying templates.
+#[derive(Debug, Default)]
+#[non_exhaustive]
+pub struct RoleTemplateReport {
+    /// Roles that did not exist and were created.
+    pub created: Vec<Role>,
+    /// Error that stopped the templates from being applied, if any.
+    ///
+    /// Roles created or updated before the error occurred are kept and
+    /// reported.
+    pub error: Option<Error>,
+    /// Roles that already matched their template.
+    pub unchanged: Vec<Role>,
+    /// Roles that existed and were updated to match their template.
+    pub updated: Vec<Role>,
+}
+
+/// Create or update a guild's roles to match templates, matching roles to
+/// templates by name.
+///
+/// Roles are created for templates without a role of the same name, and
+/// roles that differ from their template are updated. Templates are applied
+/// in order, so a template set may be applied to many guilds to keep their
+/// roles in sync.
+///
+/// Created via [`GuildRoleResource::apply_templates`].
+///
+/// [`GuildRoleResource::apply_templates`]: super::GuildRoleResource::apply_templates
+#[derive(Debug)]
+#[must_use = "template applications must be configured and executed"]
+pub struct GuildRoleTemplateApply<'a> {
+    client: &'a Client,
+    guild_id: Id<GuildMarker>,
+    reason: Option<&'a str>,
+    templates: &'a [RoleTemplate<'a>],
+}
+
+impl<'a> GuildRoleTemplateApply<'a> {
+    pub(super) const fn new(
+        client: &'a Client,
+        guild_id: Id<GuildMarker>,
+        templates: &'a [RoleTemplate<'a>],
+    ) -> Self {
+        Self {
+            client,
+            guild_id,
+            reason: None,
+            templates,
+        }
+    }
+
+    /// Attach an audit log reason to each created and updated role.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`AuditReason`] if the length is invalid.
+    ///
+    /// [`AuditReason`]: twilight_validate::request::ValidationErrorType::AuditReason
+    pub fn reason(mut self, reason: &'a str) -> Result<Self, ValidationError> {
+        validate_audit_reason(reason)?;
+        self.reason = Some(reason);
+
+        Ok(self)
+    }
+
+    /// Execute the application, returning the roles that were created,
+    /// updated, and left unchanged.
+    ///
+    /// If a role could not be created or updated then the application stops,
+    /// and the error is recorded in [`RoleTemplateReport::error`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ErrorType::Request`] if the guild's roles
+    /// could not be retrieved.
+    ///
+    /// [`ErrorType::Request`]: crate::ErrorType::Request
+    pub async fn exec(self) -> Result<RoleTemplateReport, Error> {
+        let roles = self
+            .client
+            .roles(self.guild_id)
+            .exec()
+            .await?
+            .models()
+            .await?;
+        let mut report = RoleTemplateReport::default();
+
+        if let Err(source) = self.apply(&roles, &mut report).await {
+            report.error = Some(source);
+        }
+
+        Ok(report)
+    }
+
+    /// Apply the templates to the guild's roles, recording each role in the
+    /// report as it's created, updated, or left unchanged.
+    async fn apply(&self, roles: &[Role], report: &mut RoleTemplateReport) -> Result<(), Error> {
+        let Self {
+            client,
+            guild_id,
+            reason,
+            templates,
+        } = *self;
+
+        for template in templates {
+            let Some(role) = roles.iter().find(|role| role.name == template.name) else {
+                let mut request = client
+                    .create_role(guild_id)
+                    .name(template.name)
+                    .color(template.color)
+                    .hoist(template.hoist)
+                    .mentionable(template.mentionable)
+                    .permissions(template.permissions);
+
+                if let Some(emoji) = template.unicode_emoji {
+                    request = request.unicode_emoji(emoji);
+                }
+
+                if let Some(reason) = reason {
+                    request = request.reason(reason)?;
+                }
+
+                report.created.push(request.exec().await?.model().await?);
+
+                continue;
+            };
+
+            if template.matches(role) {
+                report.unchanged.push(role.clone());
+
+                continue;
+            }
+
+            let mut request = client
+                .update_role(guild_id, role.id)
+                .color(Some(template.color))
+                .hoist(template.hoist)
+                .mentionable(template.mentionable)
+                .permissions(template.permissions);
+
+            if let Some(emoji) = template.unicode_emoji {
+                request = request.unicode_emoji(emoji);
+            }
+
+            if let Some(reason) = reason {
+                request = request.reason(reason)?;
+            }
+
+            report.updated.push(request.exec().await?.model().await?);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GuildRoleTemplateApply, RoleTemplate, RoleTemplateReport};
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_impl_all!(GuildRoleTemplateApply<'_>: Debug, Send, Sync);
+    assert_impl_all!(RoleTemplate<'_>: Clone, Copy, Debug, Eq, Hash, PartialEq, Send, Sync);
+    assert_impl_all!(RoleTemplateReport: Debug, Default, Send, Sync);
+}
@@ -0,0 +1,68 @@
+use crate::{timestamp, Error};
+use std::time::SystemTime;
+use twilight_http::{client::Client, request::guild::user::UpdateCurrentUserVoiceState};
+use twilight_model::id::{
+    marker::{ChannelMarker, GuildMarker},
+    Id,
+};
+
+/// Work with the current user's voice state in a guild.
+#[derive(Clone, Debug)]
+pub struct GuildVoiceStateCurrentUserResource<'a>(&'a Client, Id<GuildMarker>);
+
+impl<'a> GuildVoiceStateCurrentUserResource<'a> {
+    /// Create a resource instance to work with the current user's voice state
+    /// in a guild.
+    pub const fn new(client: &'a Client, guild_id: Id<GuildMarker>) -> Self {
+        Self(client, guild_id)
+    }
+
+    /// Update the current user's voice state.
+    pub const fn patch(&self, channel_id: Id<ChannelMarker>) -> UpdateCurrentUserVoiceState<'a> {
+        self.0.update_current_user_voice_state(self.1, channel_id)
+    }
+}
+
+/// RPC calls.
+impl<'a> GuildVoiceStateCurrentUserResource<'a> {
+    /// Move the current user to the audience of a stage channel, withdrawing
+    /// any request to speak.
+    pub const fn move_to_audience(
+        &self,
+        channel_id: Id<ChannelMarker>,
+    ) -> UpdateCurrentUserVoiceState<'a> {
+        self.patch(channel_id)
+            .suppress()
+            .request_to_speak_timestamp("")
+    }
+
+    /// Request to speak in a stage channel.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ErrorType::Request`] if the voice state
+    /// could not be updated.
+    ///
+    /// [`ErrorType::Request`]: crate::ErrorType::Request
+    pub async fn request_to_speak(&self, channel_id: Id<ChannelMarker>) -> Result<(), Error> {
+        let now = timestamp::from_system_time(SystemTime::now())?
+            .iso_8601()
+            .to_string();
+
+        self.patch(channel_id)
+            .request_to_speak_timestamp(&now)
+            .exec()
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GuildVoiceStateCurrentUserResource;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(GuildVoiceStateCurrentUserResource<'_>: Clone, Debug, Send, Sync);
+}
@@ -0,0 +1,48 @@
+mod current_user;
+mod user;
+
+pub use self::{
+    current_user::GuildVoiceStateCurrentUserResource, user::GuildVoiceStateUserResource,
+};
+
+use twilight_http::client::Client;
+use twilight_model::id::{
+    marker::{GuildMarker, UserMarker},
+    Id,
+};
+
+/// Work with a guild's voice states.
+#[derive(Clone, Debug)]
+pub struct GuildVoiceStateResource<'a>(&'a Client, Id<GuildMarker>);
+
+impl<'a> GuildVoiceStateResource<'a> {
+    /// Create a resource instance to work with a guild's voice states.
+    pub const fn new(client: &'a Client, guild_id: Id<GuildMarker>) -> Self {
+        Self(client, guild_id)
+    }
+}
+
+/// 1:1 guild voice state relationships.
+impl<'a> GuildVoiceStateResource<'a> {
+    /// Work with the current user's voice state.
+    pub const fn current_user(&self) -> GuildVoiceStateCurrentUserResource<'a> {
+        GuildVoiceStateCurrentUserResource::new(self.0, self.1)
+    }
+}
+
+/// 1:M guild voice state relationships.
+impl<'a> GuildVoiceStateResource<'a> {
+    /// Work with a user's voice state.
+    pub const fn user(&self, user_id: Id<UserMarker>) -> GuildVoiceStateUserResource<'a> {
+        GuildVoiceStateUserResource::new(self.0, self.1, user_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GuildVoiceStateResource;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(GuildVoiceStateResource<'_>: Clone, Debug, Send, Sync);
+}
@@ -0,0 +1,89 @@
+use crate::Error;
+use serde::Serialize;
+use twilight_http::{
+    client::Client,
+    request::{guild::user::UpdateUserVoiceState, Request},
+    response::marker::EmptyBody,
+    routing::Route,
+};
+use twilight_model::id::{
+    marker::{ChannelMarker, GuildMarker, UserMarker},
+    Id,
+};
+
+/// Work with a user's voice state in a guild.
+#[derive(Clone, Debug)]
+pub struct GuildVoiceStateUserResource<'a>(&'a Client, Id<GuildMarker>, Id<UserMarker>);
+
+impl<'a> GuildVoiceStateUserResource<'a> {
+    /// Create a resource instance to work with a user's voice state in a
+    /// guild.
+    pub const fn new(
+        client: &'a Client,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+    ) -> Self {
+        Self(client, guild_id, user_id)
+    }
+
+    /// Update the user's voice state.
+    pub const fn patch(&self, channel_id: Id<ChannelMarker>) -> UpdateUserVoiceState<'a> {
+        self.0.update_user_voice_state(self.1, self.2, channel_id)
+    }
+}
+
+/// RPC calls.
+impl<'a> GuildVoiceStateUserResource<'a> {
+    /// Invite the user to speak in a stage channel.
+    ///
+    /// The user is unsuppressed; users other than bots are prompted to
+    /// become a speaker, while bots become speakers immediately.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ErrorType::Request`] if the voice state
+    /// could not be updated.
+    ///
+    /// [`ErrorType::Request`]: crate::ErrorType::Request
+    pub async fn invite_to_speak(&self, channel_id: Id<ChannelMarker>) -> Result<(), Error> {
+        // twilight-http 0.11's `UpdateUserVoiceState::suppress` can only
+        // suppress a user, so unsuppressing requires a raw request.
+        let request = Request::builder(&Route::UpdateUserVoiceState {
+            guild_id: self.1.get(),
+            user_id: self.2.get(),
+        })
+        .json(&UpdateUserVoiceStateFields {
+            channel_id,
+            suppress: false,
+        })?
+        .build();
+
+        self.0.request::<EmptyBody>(request).await?;
+
+        Ok(())
+    }
+
+    /// Move the user to the audience of a stage channel.
+    pub const fn move_to_audience(
+        &self,
+        channel_id: Id<ChannelMarker>,
+    ) -> UpdateUserVoiceState<'a> {
+        self.patch(channel_id).suppress()
+    }
+}
+
+/// Body of a request to update a user's voice state.
+#[derive(Serialize)]
+struct UpdateUserVoiceStateFields {
+    channel_id: Id<ChannelMarker>,
+    suppress: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GuildVoiceStateUserResource;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(GuildVoiceStateUserResource<'_>: Clone, Debug, Send, Sync);
+}
@@ -0,0 +1,93 @@
+use crate::{bulk::DEFAULT_CONCURRENCY, error, Error};
+use futures_util::{
+    future,
+    stream::{self, StreamExt, TryStreamExt},
+};
+use std::collections::{HashMap, HashSet};
+use twilight_http::client::Client;
+use twilight_model::{
+    channel::webhook::Webhook,
+    id::{
+        marker::{ChannelMarker, GuildMarker, UserMarker, WebhookMarker},
+        Id,
+    },
+};
+
+/// All of a guild's webhooks, grouped by channel and creator.
+///
+/// Created via [`GuildWebhookResource::inventory`].
+///
+/// [`GuildWebhookResource::inventory`]: super::GuildWebhookResource::inventory
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct WebhookInventory {
+    /// Webhooks grouped by the ID of the channel they belong to.
+    pub channels: HashMap<Id<ChannelMarker>, Vec<Webhook>>,
+    /// IDs of webhooks grouped by the ID of the user that created them.
+    ///
+    /// Webhooks without a known creator are not included.
+    pub creators: HashMap<Id<UserMarker>, Vec<Id<WebhookMarker>>>,
+    /// IDs of webhooks created by users that are no longer in the guild.
+    pub orphaned: Vec<Id<WebhookMarker>>,
+}
+
+/// Retrieve and group all of a guild's webhooks.
+pub(super) async fn inventory(
+    client: &Client,
+    guild_id: Id<GuildMarker>,
+) -> Result<WebhookInventory, Error> {
+    let webhooks = client
+        .guild_webhooks(guild_id)
+        .exec()
+        .await?
+        .models()
+        .await?;
+
+    let mut channels = HashMap::<_, Vec<_>>::new();
+    let mut creators = HashMap::<_, Vec<_>>::new();
+
+    for webhook in webhooks {
+        if let Some(user) = &webhook.user {
+            creators.entry(user.id).or_default().push(webhook.id);
+        }
+
+        channels
+            .entry(webhook.channel_id)
+            .or_default()
+            .push(webhook);
+    }
+
+    let departed = stream::iter(creators.keys().copied())
+        .map(|user_id| async move {
+            match client.guild_member(guild_id, user_id).exec().await {
+                Ok(_) => Ok(None),
+                Err(source) if error::is_not_found(&source) => Ok(Some(user_id)),
+                Err(source) => Err(Error::from(source)),
+            }
+        })
+        .buffer_unordered(DEFAULT_CONCURRENCY)
+        .try_filter_map(future::ok)
+        .try_collect::<HashSet<_>>()
+        .await?;
+
+    let orphaned = creators
+        .iter()
+        .filter(|(user_id, _)| departed.contains(user_id))
+        .flat_map(|(_, webhook_ids)| webhook_ids.iter().copied())
+        .collect();
+
+    Ok(WebhookInventory {
+        channels,
+        creators,
+        orphaned,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WebhookInventory;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(WebhookInventory: Clone, Debug, Eq, Send, Sync);
+}
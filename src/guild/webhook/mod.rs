@@ -0,0 +1,63 @@
+mod inventory;
+mod rotate;
+
+pub use self::{
+    inventory::WebhookInventory,
+    rotate::{GuildWebhookRotation, WebhookRotation},
+};
+
+use crate::Error;
+use twilight_http::{client::Client, request::guild::GetGuildWebhooks};
+use twilight_model::id::{
+    marker::{GuildMarker, WebhookMarker},
+    Id,
+};
+
+/// Work with a guild's webhooks.
+#[derive(Clone, Debug)]
+pub struct GuildWebhookResource<'a>(&'a Client, Id<GuildMarker>);
+
+impl<'a> GuildWebhookResource<'a> {
+    /// Create a resource instance to work with a guild's webhooks.
+    pub const fn new(client: &'a Client, guild_id: Id<GuildMarker>) -> Self {
+        Self(client, guild_id)
+    }
+
+    /// List a guild's webhooks.
+    pub const fn list(&self) -> GetGuildWebhooks<'a> {
+        self.0.guild_webhooks(self.1)
+    }
+}
+
+/// RPC calls.
+impl<'a> GuildWebhookResource<'a> {
+    /// List a guild's webhooks grouped by channel and creator, flagging
+    /// webhooks created by users that are no longer in the guild.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ErrorType::Request`] if the guild's
+    /// webhooks or the creators' memberships could not be retrieved.
+    ///
+    /// [`ErrorType::Request`]: crate::ErrorType::Request
+    pub async fn inventory(&self) -> Result<WebhookInventory, Error> {
+        inventory::inventory(self.0, self.1).await
+    }
+
+    /// Replace a webhook with a new one in the same channel, invalidating its
+    /// URL.
+    ///
+    /// Refer to [`GuildWebhookRotation`] for what is carried over.
+    pub const fn rotate(&self, webhook_id: Id<WebhookMarker>) -> GuildWebhookRotation<'a> {
+        GuildWebhookRotation::new(self.0, self.1, webhook_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GuildWebhookResource;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(GuildWebhookResource<'_>: Clone, Debug, Send, Sync);
+}
@@ -0,0 +1,203 @@
+use crate::{image, webhook::WebhookUrl, Error, ErrorType};
+use twilight_http::{client::Client, request::AuditLogReason};
+use twilight_model::{
+    channel::webhook::Webhook,
+    id::{
+        marker::{GuildMarker, WebhookMarker},
+        Id,
+    },
+};
+use twilight_validate::request::{audit_reason as validate_audit_reason, ValidationError};
+
+/// Webhook that replaced a rotated webhook.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct WebhookRotation {
+    /// Error that occurred deleting the previous webhook, if any.
+    ///
+    /// If this is set then the previous webhook and its URL still exist.
+    pub delete_error: Option<Error>,
+    /// ID of the previous webhook.
+    pub previous_id: Id<WebhookMarker>,
+    /// URL of the new webhook, if Discord returned its token.
+    pub url: Option<String>,
+    /// New webhook.
+    pub webhook: Webhook,
+}
+
+/// Replace a webhook with a new one, invalidating its URL.
+///
+/// The new webhook is created in the same channel with the same name and
+/// avatar before the old webhook is deleted. Unless an avatar is provided via
+/// [`avatar`], the previous avatar is downloaded from Discord's CDN, which
+/// requires the `cdn` feature. Animated avatars are downloaded as GIFs.
+///
+/// Created via [`GuildWebhookResource::rotate`].
+///
+/// [`GuildWebhookResource::rotate`]: super::GuildWebhookResource::rotate
+/// [`avatar`]: Self::avatar
+#[derive(Debug)]
+#[must_use = "rotations must be configured and executed"]
+pub struct GuildWebhookRotation<'a> {
+    avatar: Option<&'a [u8]>,
+    client: &'a Client,
+    guild_id: Id<GuildMarker>,
+    reason: Option<&'a str>,
+    webhook_id: Id<WebhookMarker>,
+}
+
+impl<'a> GuildWebhookRotation<'a> {
+    pub(super) const fn new(
+        client: &'a Client,
+        guild_id: Id<GuildMarker>,
+        webhook_id: Id<WebhookMarker>,
+    ) -> Self {
+        Self {
+            avatar: None,
+            client,
+            guild_id,
+            reason: None,
+            webhook_id,
+        }
+    }
+
+    /// Set the avatar of the new webhook instead of carrying over the
+    /// previous webhook's avatar.
+    ///
+    /// This must be a Data URI, in the form of
+    /// `data:image/{type};base64,{data}`.
+    pub const fn avatar(mut self, avatar: &'a [u8]) -> Self {
+        self.avatar = Some(avatar);
+
+        self
+    }
+
+    /// Attach an audit log reason to the creation and deletion.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`AuditReason`] if the length is invalid.
+    ///
+    /// [`AuditReason`]: twilight_validate::request::ValidationErrorType::AuditReason
+    pub fn reason(mut self, reason: &'a str) -> Result<Self, ValidationError> {
+        validate_audit_reason(reason)?;
+        self.reason = Some(reason);
+
+        Ok(self)
+    }
+
+    /// Execute the rotation, returning the new webhook and its URL.
+    ///
+    /// If the new webhook is created but the previous webhook could not be
+    /// deleted then the rotation is still returned, with the error recorded
+    /// in [`WebhookRotation::delete_error`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ErrorType::ImageUnavailable`] if the
+    /// previous webhook has an avatar, no avatar was provided, and the `cdn`
+    /// feature is disabled.
+    ///
+    /// Returns an error of type [`ErrorType::Request`] if the webhook or its
+    /// avatar could not be retrieved, or the new webhook could not be
+    /// created.
+    ///
+    /// Returns an error of type [`ErrorType::UnknownWebhook`] if the webhook
+    /// belongs to another guild.
+    ///
+    /// Returns an error of type [`ErrorType::UnnamedWebhook`] if the webhook
+    /// does not have a name.
+    ///
+    /// [`ErrorType::ImageUnavailable`]: crate::ErrorType::ImageUnavailable
+    /// [`ErrorType::Request`]: crate::ErrorType::Request
+    /// [`ErrorType::UnknownWebhook`]: crate::ErrorType::UnknownWebhook
+    /// [`ErrorType::UnnamedWebhook`]: crate::ErrorType::UnnamedWebhook
+    pub async fn exec(self) -> Result<WebhookRotation, Error> {
+        let Self {
+            avatar,
+            client,
+            guild_id,
+            reason,
+            webhook_id,
+        } = self;
+
+        let previous = client.webhook(webhook_id).exec().await?.model().await?;
+
+        if previous.guild_id != Some(guild_id) {
+            return Err(Error {
+                kind: ErrorType::UnknownWebhook { webhook_id },
+                source: None,
+            });
+        }
+
+        let name = previous.name.as_deref().ok_or(Error {
+            kind: ErrorType::UnnamedWebhook { webhook_id },
+            source: None,
+        })?;
+
+        let previous_avatar = match (avatar, previous.avatar) {
+            (None, Some(hash)) => {
+                let extension = image::extension(hash);
+                let image =
+                    image::fetch(&format!("avatars/{webhook_id}/{hash}.{extension}")).await?;
+
+                Some(image::data_uri(
+                    image::mime_type(&image).unwrap_or("image/png"),
+                    &image,
+                ))
+            }
+            _ => None,
+        };
+
+        let mut create = client.create_webhook(previous.channel_id, name)?;
+
+        if let Some(avatar) = avatar.or(previous_avatar.as_deref()) {
+            create = create.avatar(avatar);
+        }
+
+        if let Some(reason) = reason {
+            create = create.reason(reason)?;
+        }
+
+        let webhook = create.exec().await?.model().await?;
+
+        let url = webhook
+            .token
+            .as_deref()
+            .map(|token| WebhookUrl::new(webhook.id, token).to_string());
+
+        Ok(WebhookRotation {
+            delete_error: delete(client, webhook_id, reason).await.err(),
+            previous_id: webhook_id,
+            url,
+            webhook,
+        })
+    }
+}
+
+/// Delete a webhook with an optional audit log reason.
+async fn delete(
+    client: &Client,
+    webhook_id: Id<WebhookMarker>,
+    reason: Option<&str>,
+) -> Result<(), Error> {
+    let mut request = client.delete_webhook(webhook_id);
+
+    if let Some(reason) = reason {
+        request = request.reason(reason)?;
+    }
+
+    request.exec().await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GuildWebhookRotation, WebhookRotation};
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(GuildWebhookRotation<'_>: Debug, Send, Sync);
+    assert_impl_all!(WebhookRotation: Debug, Send, Sync);
+}
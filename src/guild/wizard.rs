@@ -0,0 +1,758 @@
+use super::RoleTemplate;
+use crate::{Error, ErrorType};
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+};
+use twilight_http::{
+    client::Client,
+    request::guild::create_guild::{
+        CategoryFields, GuildChannelFields, RoleFields, TextFieldsBuilder, VoiceFieldsBuilder,
+    },
+};
+use twilight_model::{
+    channel::ChannelType,
+    guild::{PartialGuild, Permissions},
+    http::permission_overwrite::{PermissionOverwrite, PermissionOverwriteType},
+    id::{
+        marker::{ChannelMarker, RoleMarker},
+        Id,
+    },
+};
+use twilight_validate::channel::name as validate_channel_name;
+
+/// Name of the `@everyone` role.
+const EVERYONE: &str = "@everyone";
+
+/// Guild that was created by a wizard, along with the IDs of its declared
+/// channels and roles.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct GuildWizardReport {
+    /// IDs of created channels, keyed by their declared names.
+    ///
+    /// Channels that could not be matched to their declarations are not
+    /// included, and are listed in [`unmatched_channels`] instead.
+    ///
+    /// [`unmatched_channels`]: Self::unmatched_channels
+    pub channels: HashMap<String, Id<ChannelMarker>>,
+    /// Created guild.
+    pub guild: PartialGuild,
+    /// IDs of created roles, keyed by their declared names.
+    ///
+    /// The `@everyone` role is always included.
+    pub roles: HashMap<String, Id<RoleMarker>>,
+    /// Declared names of channels that could not be matched to a created
+    /// channel.
+    pub unmatched_channels: Vec<String>,
+}
+
+/// Channel declared in a wizard.
+#[derive(Debug)]
+struct WizardChannel<'a> {
+    category: Option<&'a str>,
+    kind: ChannelType,
+    name: &'a str,
+}
+
+/// Permission overwrite declared in a wizard.
+#[derive(Debug)]
+struct WizardOverwrite<'a> {
+    allow: Permissions,
+    channel: &'a str,
+    deny: Permissions,
+    role: &'a str,
+}
+
+/// Name, kind, and category name of a declared channel.
+type DeclaredChannel<'a> = (&'a str, ChannelType, Option<&'a str>);
+
+/// ID, name, kind, and parent ID of a created channel.
+type CreatedChannel<'a> = (
+    Id<ChannelMarker>,
+    &'a str,
+    ChannelType,
+    Option<Id<ChannelMarker>>,
+);
+
+/// Channels and roles resolved into the placeholder ID form Discord expects.
+#[derive(Debug)]
+struct Resolved<'a> {
+    afk_channel_id: Option<Id<ChannelMarker>>,
+    channels: Vec<GuildChannelFields>,
+    /// Declared channels, in the order they are created.
+    declared: Vec<DeclaredChannel<'a>>,
+    everyone: Option<RoleFields>,
+    roles: Vec<RoleFields>,
+    system_channel_id: Option<Id<ChannelMarker>>,
+}
+
+/// Create a guild with categories, channels, roles, and permission overwrites
+/// declared by name.
+///
+/// Names are resolved into the placeholder IDs that Discord expects when
+/// creating a guild, and the real IDs are returned once the guild has been
+/// created. Channel names must be unique among channels and role names must
+/// be unique among roles. The `@everyone` role can be referred to by the name
+/// `@everyone`.
+///
+/// Created via [`GuildResource::wizard`].
+///
+/// [`GuildResource::wizard`]: super::GuildResource::wizard
+#[derive(Debug)]
+#[must_use = "guild wizards must be configured and executed"]
+pub struct GuildWizard<'a> {
+    afk_channel: Option<&'a str>,
+    channels: Vec<WizardChannel<'a>>,
+    client: &'a Client,
+    icon: Option<&'a [u8]>,
+    name: &'a str,
+    overwrites: Vec<WizardOverwrite<'a>>,
+    roles: Vec<RoleTemplate<'a>>,
+    system_channel: Option<&'a str>,
+}
+
+impl<'a> GuildWizard<'a> {
+    pub(super) const fn new(client: &'a Client, name: &'a str) -> Self {
+        Self {
+            afk_channel: None,
+            channels: Vec::new(),
+            client,
+            icon: None,
+            name,
+            overwrites: Vec::new(),
+            roles: Vec::new(),
+            system_channel: None,
+        }
+    }
+
+    /// Set the AFK voice channel by name.
+    ///
+    /// The name must refer to a declared voice channel.
+    pub const fn afk_channel(mut self, name: &'a str) -> Self {
+        self.afk_channel = Some(name);
+
+        self
+    }
+
+    /// Declare a category.
+    pub fn category(mut self, name: &'a str) -> Self {
+        self.channels.push(WizardChannel {
+            category: None,
+            kind: ChannelType::GuildCategory,
+            name,
+        });
+
+        self
+    }
+
+    /// Set the icon of the guild.
+    ///
+    /// This must be a Data URI, in the form of
+    /// `data:image/{type};base64,{data}`.
+    pub const fn icon(mut self, icon: &'a [u8]) -> Self {
+        self.icon = Some(icon);
+
+        self
+    }
+
+    /// Declare a permission overwrite for a role in a channel or category.
+    pub fn overwrite(
+        mut self,
+        channel: &'a str,
+        role: &'a str,
+        allow: Permissions,
+        deny: Permissions,
+    ) -> Self {
+        self.overwrites.push(WizardOverwrite {
+            allow,
+            channel,
+            deny,
+            role,
+        });
+
+        self
+    }
+
+    /// Declare a role.
+    ///
+    /// A template named `@everyone` configures the `@everyone` role. Unicode
+    /// emoji icons are not applied, since new guilds do not have the
+    /// `ROLE_ICONS` feature.
+    pub fn role(mut self, template: RoleTemplate<'a>) -> Self {
+        self.roles.push(template);
+
+        self
+    }
+
+    /// Set the channel where system messages are posted by name.
+    pub const fn system_channel(mut self, name: &'a str) -> Self {
+        self.system_channel = Some(name);
+
+        self
+    }
+
+    /// Declare a text channel, optionally within a category.
+    pub fn text_channel(mut self, name: &'a str, category: Option<&'a str>) -> Self {
+        self.channels.push(WizardChannel {
+            category,
+            kind: ChannelType::GuildText,
+            name,
+        });
+
+        self
+    }
+
+    /// Declare a voice channel, optionally within a category.
+    pub fn voice_channel(mut self, name: &'a str, category: Option<&'a str>) -> Self {
+        self.channels.push(WizardChannel {
+            category,
+            kind: ChannelType::GuildVoice,
+            name,
+        });
+
+        self
+    }
+
+    /// Execute the wizard, returning the created guild and the IDs of its
+    /// declared channels and roles.
+    ///
+    /// Created channels are matched to their declarations by name, kind, and
+    /// category. Text channel names are compared the way Discord normalizes
+    /// them, in lowercase and with spaces replaced by dashes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ErrorType::DuplicateName`] if a channel or
+    /// role name was declared more than once.
+    ///
+    /// Returns an error of type [`ErrorType::Request`] if the guild could not
+    /// be created or its channels could not be retrieved.
+    ///
+    /// Returns an error of type [`ErrorType::UnknownName`] if a category,
+    /// channel, or role was referenced without being declared, or if the AFK
+    /// channel is not a declared voice channel.
+    ///
+    /// Returns an error of type [`ErrorType::Validation`] if the name of the
+    /// guild or a channel is invalid, or if there are too many channels or
+    /// roles.
+    ///
+    /// [`ErrorType::DuplicateName`]: crate::ErrorType::DuplicateName
+    /// [`ErrorType::Request`]: crate::ErrorType::Request
+    /// [`ErrorType::UnknownName`]: crate::ErrorType::UnknownName
+    /// [`ErrorType::Validation`]: crate::ErrorType::Validation
+    pub async fn exec(self) -> Result<GuildWizardReport, Error> {
+        let Resolved {
+            afk_channel_id,
+            channels,
+            declared,
+            everyone,
+            roles,
+            system_channel_id,
+        } = self.resolve()?;
+
+        let client = self.client;
+
+        let mut request = client
+            .create_guild(self.name.to_owned())
+            .map_err(Error::validation)?;
+
+        if let Some(everyone) = everyone {
+            request = request.override_everyone(everyone);
+        }
+
+        if !roles.is_empty() {
+            request = request.roles(roles).map_err(Error::validation)?;
+        }
+
+        if !channels.is_empty() {
+            request = request.channels(channels).map_err(Error::validation)?;
+        }
+
+        if let Some(afk_channel_id) = afk_channel_id {
+            request = request.afk_channel_id(afk_channel_id);
+        }
+
+        if let Some(icon) = self.icon {
+            request = request.icon(icon);
+        }
+
+        if let Some(system_channel_id) = system_channel_id {
+            request = request.system_channel_id(system_channel_id);
+        }
+
+        let guild = request.exec().await?.model().await?;
+
+        let created = client
+            .guild_channels(guild.id)
+            .exec()
+            .await?
+            .models()
+            .await?;
+        let created = created
+            .iter()
+            .map(|channel| {
+                (
+                    channel.id,
+                    channel.name.as_deref().unwrap_or_default(),
+                    channel.kind,
+                    channel.parent_id,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let channels = match_channels(&declared, &created);
+        let unmatched_channels = declared
+            .iter()
+            .filter(|(name, ..)| !channels.contains_key(*name))
+            .map(|(name, ..)| (*name).to_owned())
+            .collect();
+
+        let roles = guild
+            .roles
+            .iter()
+            .filter(|role| {
+                role.id == guild.id.cast()
+                    || self
+                        .roles
+                        .iter()
+                        .any(|template| template.name() == role.name)
+            })
+            .map(|role| {
+                let name = if role.id == guild.id.cast() {
+                    EVERYONE.to_owned()
+                } else {
+                    role.name.clone()
+                };
+
+                (name, role.id)
+            })
+            .collect();
+
+        Ok(GuildWizardReport {
+            channels,
+            guild,
+            roles,
+            unmatched_channels,
+        })
+    }
+
+    /// Resolve declared names into placeholder IDs.
+    ///
+    /// Categories are created first so that channels can refer to them.
+    fn resolve(&self) -> Result<Resolved<'a>, Error> {
+        // The placeholder ID of the @everyone role is always 1.
+        let mut role_ids = HashMap::from([(EVERYONE, Id::<RoleMarker>::new(1))]);
+        let mut everyone = None;
+        let mut next_role_id = 2;
+        let mut roles = Vec::new();
+
+        for template in &self.roles {
+            if template.name() == EVERYONE {
+                if everyone.is_some() {
+                    return Err(duplicate_name(EVERYONE));
+                }
+
+                everyone = Some(template.fields(role_ids[EVERYONE]));
+
+                continue;
+            }
+
+            let id = Id::new(next_role_id);
+            next_role_id += 1;
+
+            if role_ids.insert(template.name(), id).is_some() {
+                return Err(duplicate_name(template.name()));
+            }
+
+            roles.push(template.fields(id));
+        }
+
+        let ordered = self
+            .channels
+            .iter()
+            .filter(|channel| channel.kind == ChannelType::GuildCategory)
+            .chain(
+                self.channels
+                    .iter()
+                    .filter(|channel| channel.kind != ChannelType::GuildCategory),
+            )
+            .collect::<Vec<_>>();
+
+        let mut channel_ids = HashMap::new();
+        let mut text_channel_names = HashSet::new();
+
+        for (id, channel) in (1..).zip(&ordered) {
+            // Text channels whose names normalize to the same name couldn't
+            // be told apart once created.
+            let duplicate_text = channel.kind == ChannelType::GuildText
+                && !text_channel_names.insert(normalize_text_channel_name(channel.name));
+
+            if channel_ids
+                .insert(channel.name, (Id::<ChannelMarker>::new(id), channel.kind))
+                .is_some()
+                || duplicate_text
+            {
+                return Err(duplicate_name(channel.name));
+            }
+        }
+
+        let mut overwrites = HashMap::<_, Vec<_>>::new();
+
+        for overwrite in &self.overwrites {
+            let (channel_id, _) = channel_ids
+                .get(overwrite.channel)
+                .ok_or_else(|| unknown_name(overwrite.channel))?;
+            let role_id = role_ids
+                .get(overwrite.role)
+                .ok_or_else(|| unknown_name(overwrite.role))?;
+
+            overwrites
+                .entry(*channel_id)
+                .or_default()
+                .push(PermissionOverwrite {
+                    allow: Some(overwrite.allow),
+                    deny: Some(overwrite.deny),
+                    id: role_id.cast(),
+                    kind: PermissionOverwriteType::Role,
+                });
+        }
+
+        let mut channels = Vec::with_capacity(ordered.len());
+
+        for channel in &ordered {
+            let (id, _) = channel_ids[channel.name];
+            let overwrites = overwrites.remove(&id);
+
+            let parent_id = match channel.category {
+                Some(category) => match channel_ids.get(category) {
+                    Some((parent_id, ChannelType::GuildCategory)) => Some(*parent_id),
+                    _ => return Err(unknown_name(category)),
+                },
+                None => None,
+            };
+
+            channels.push(channel_fields(channel, id, parent_id, overwrites)?);
+        }
+
+        let channel_id = |name: Option<&str>| {
+            name.map(|name| {
+                channel_ids
+                    .get(name)
+                    .map(|(id, _)| *id)
+                    .ok_or_else(|| unknown_name(name))
+            })
+            .transpose()
+        };
+
+        if let Some(name) = self.afk_channel {
+            if !matches!(channel_ids.get(name), Some((_, ChannelType::GuildVoice))) {
+                return Err(unknown_name(name));
+            }
+        }
+
+        Ok(Resolved {
+            afk_channel_id: channel_id(self.afk_channel)?,
+            channels,
+            declared: ordered
+                .iter()
+                .map(|channel| (channel.name, channel.kind, channel.category))
+                .collect(),
+            everyone,
+            roles,
+            system_channel_id: channel_id(self.system_channel)?,
+        })
+    }
+}
+
+/// Match created channels to their declarations by name, kind, and category,
+/// returning the IDs of the channels keyed by their declared names.
+fn match_channels(
+    declared: &[DeclaredChannel<'_>],
+    created: &[CreatedChannel<'_>],
+) -> HashMap<String, Id<ChannelMarker>> {
+    let find = |name: &str, kind, parent_id| {
+        let name = if kind == ChannelType::GuildText {
+            Cow::Owned(normalize_text_channel_name(name))
+        } else {
+            Cow::Borrowed(name)
+        };
+
+        created
+            .iter()
+            .find(|(_, created_name, created_kind, created_parent_id)| {
+                (*created_name, *created_kind, *created_parent_id)
+                    == (name.as_ref(), kind, parent_id)
+            })
+            .map(|(id, ..)| *id)
+    };
+
+    let categories = declared
+        .iter()
+        .filter(|(_, kind, _)| *kind == ChannelType::GuildCategory)
+        .filter_map(|(name, kind, _)| Some((*name, find(name, *kind, None)?)))
+        .collect::<HashMap<_, _>>();
+
+    declared
+        .iter()
+        .filter_map(|(name, kind, category)| {
+            let parent_id = match category {
+                Some(category) => Some(*categories.get(category)?),
+                None => None,
+            };
+
+            Some(((*name).to_owned(), find(name, *kind, parent_id)?))
+        })
+        .collect()
+}
+
+/// Normalize a text channel's name the way Discord does, lowercasing it and
+/// replacing spaces with dashes.
+fn normalize_text_channel_name(name: &str) -> String {
+    name.split_whitespace()
+        .collect::<Vec<_>>()
+        .join("-")
+        .to_lowercase()
+}
+
+/// Error for a name that was declared more than once.
+fn duplicate_name(name: &str) -> Error {
+    Error {
+        kind: ErrorType::DuplicateName {
+            name: name.to_owned(),
+        },
+        source: None,
+    }
+}
+
+/// Error for a name that was referenced without being declared.
+fn unknown_name(name: &str) -> Error {
+    Error {
+        kind: ErrorType::UnknownName {
+            name: name.to_owned(),
+        },
+        source: None,
+    }
+}
+
+/// Fields of a declared channel to create alongside a guild.
+fn channel_fields(
+    channel: &WizardChannel<'_>,
+    id: Id<ChannelMarker>,
+    parent_id: Option<Id<ChannelMarker>>,
+    permission_overwrites: Option<Vec<PermissionOverwrite>>,
+) -> Result<GuildChannelFields, Error> {
+    let name = channel.name.to_owned();
+
+    Ok(match channel.kind {
+        ChannelType::GuildCategory => {
+            validate_channel_name(&name).map_err(Error::validation)?;
+
+            GuildChannelFields::Category(CategoryFields {
+                id,
+                kind: ChannelType::GuildCategory,
+                name,
+                permission_overwrites,
+            })
+        }
+        ChannelType::GuildVoice => {
+            let mut fields = VoiceFieldsBuilder::new(name)
+                .map_err(Error::validation)?
+                .build();
+            fields.id = id;
+            fields.parent_id = parent_id;
+            fields.permission_overwrites = permission_overwrites;
+
+            GuildChannelFields::Voice(fields)
+        }
+        _ => {
+            let mut fields = TextFieldsBuilder::new(name)
+                .map_err(Error::validation)?
+                .build();
+            fields.id = id;
+            fields.parent_id = parent_id;
+            fields.permission_overwrites = permission_overwrites;
+
+            GuildChannelFields::Text(fields)
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{match_channels, normalize_text_channel_name, GuildWizard, GuildWizardReport};
+    use crate::{guild::RoleTemplate, Error, ErrorType};
+    use static_assertions::assert_impl_all;
+    use std::{collections::HashMap, fmt::Debug};
+    use twilight_http::{client::Client, request::guild::create_guild::GuildChannelFields};
+    use twilight_model::{
+        channel::ChannelType,
+        guild::Permissions,
+        id::{marker::RoleMarker, Id},
+    };
+
+    assert_impl_all!(GuildWizard<'_>: Debug, Send, Sync);
+    assert_impl_all!(GuildWizardReport: Clone, Debug, Eq, Send, Sync);
+
+    #[test]
+    fn resolve() -> Result<(), Error> {
+        let client = Client::new(String::new());
+
+        let resolved = GuildWizard::new(&client, "guild")
+            .text_channel("rules", None)
+            .voice_channel("lounge", Some("general"))
+            .category("general")
+            .role(RoleTemplate::new("@everyone", Permissions::empty()))
+            .role(RoleTemplate::new("moderator", Permissions::KICK_MEMBERS))
+            .overwrite(
+                "general",
+                "@everyone",
+                Permissions::empty(),
+                Permissions::CONNECT,
+            )
+            .overwrite(
+                "general",
+                "moderator",
+                Permissions::CONNECT,
+                Permissions::empty(),
+            )
+            .afk_channel("lounge")
+            .resolve()?;
+
+        assert_eq!(Some(Id::new(3)), resolved.afk_channel_id);
+        assert_eq!(
+            vec![
+                ("general", ChannelType::GuildCategory, None),
+                ("rules", ChannelType::GuildText, None),
+                ("lounge", ChannelType::GuildVoice, Some("general")),
+            ],
+            resolved.declared
+        );
+        assert_eq!(Some(Id::new(1)), resolved.everyone.map(|role| role.id));
+        assert_eq!(
+            vec![Id::new(2)],
+            resolved
+                .roles
+                .iter()
+                .map(|role| role.id)
+                .collect::<Vec<_>>()
+        );
+
+        let GuildChannelFields::Category(category) = &resolved.channels[0] else {
+            panic!("first channel is not a category");
+        };
+        let overwrites = category
+            .permission_overwrites
+            .as_deref()
+            .unwrap_or_default();
+        assert_eq!(
+            vec![Id::<RoleMarker>::new(1), Id::new(2)],
+            overwrites
+                .iter()
+                .map(|overwrite| overwrite.id.cast())
+                .collect::<Vec<_>>()
+        );
+
+        let GuildChannelFields::Voice(lounge) = &resolved.channels[2] else {
+            panic!("last channel is not a voice channel");
+        };
+        assert_eq!(Some(Id::new(1)), lounge.parent_id);
+
+        Ok(())
+    }
+
+    #[test]
+    fn resolve_errors() {
+        let client = Client::new(String::new());
+
+        let duplicate = GuildWizard::new(&client, "guild")
+            .category("general")
+            .text_channel("general", None)
+            .resolve();
+        assert!(matches!(
+            duplicate.map_err(|error| error.into_parts().0),
+            Err(ErrorType::DuplicateName { name }) if name == "general"
+        ));
+
+        let unknown = GuildWizard::new(&client, "guild")
+            .text_channel("rules", Some("info"))
+            .resolve();
+        assert!(matches!(
+            unknown.map_err(|error| error.into_parts().0),
+            Err(ErrorType::UnknownName { name }) if name == "info"
+        ));
+
+        let duplicate = GuildWizard::new(&client, "guild")
+            .text_channel("Read Me", None)
+            .text_channel("read-me", None)
+            .resolve();
+        assert!(matches!(
+            duplicate.map_err(|error| error.into_parts().0),
+            Err(ErrorType::DuplicateName { name }) if name == "read-me"
+        ));
+
+        let unknown = GuildWizard::new(&client, "guild")
+            .text_channel("rules", None)
+            .afk_channel("rules")
+            .resolve();
+        assert!(matches!(
+            unknown.map_err(|error| error.into_parts().0),
+            Err(ErrorType::UnknownName { name }) if name == "rules"
+        ));
+
+        let unknown = GuildWizard::new(&client, "guild")
+            .text_channel("rules", None)
+            .overwrite("rules", "admin", Permissions::empty(), Permissions::empty())
+            .resolve();
+        assert!(matches!(
+            unknown.map_err(|error| error.into_parts().0),
+            Err(ErrorType::UnknownName { name }) if name == "admin"
+        ));
+    }
+
+    #[test]
+    fn matches_channels() {
+        let declared = [
+            ("general", ChannelType::GuildCategory, None),
+            ("voice", ChannelType::GuildCategory, None),
+            ("chat", ChannelType::GuildText, Some("general")),
+            ("lounge", ChannelType::GuildVoice, Some("voice")),
+            ("Rules", ChannelType::GuildText, None),
+        ];
+        let created = [
+            (
+                Id::new(10),
+                "lounge",
+                ChannelType::GuildVoice,
+                Some(Id::new(12)),
+            ),
+            (
+                Id::new(11),
+                "chat",
+                ChannelType::GuildText,
+                Some(Id::new(13)),
+            ),
+            (Id::new(12), "voice", ChannelType::GuildCategory, None),
+            (Id::new(13), "general", ChannelType::GuildCategory, None),
+            (Id::new(14), "rules", ChannelType::GuildText, None),
+        ];
+
+        assert_eq!(
+            HashMap::from([
+                ("Rules".to_owned(), Id::new(14)),
+                ("chat".to_owned(), Id::new(11)),
+                ("general".to_owned(), Id::new(13)),
+                ("lounge".to_owned(), Id::new(10)),
+                ("voice".to_owned(), Id::new(12)),
+            ]),
+            match_channels(&declared, &created)
+        );
+    }
+
+    #[test]
+    fn normalizes_text_channel_names() {
+        assert_eq!("read-me", normalize_text_channel_name("Read Me"));
+        assert_eq!("off-topic", normalize_text_channel_name(" Off  Topic "));
+        assert_eq!("general", normalize_text_channel_name("general"));
+    }
+}
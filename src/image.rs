@@ -0,0 +1,150 @@
+use crate::Error;
+#[cfg(not(feature = "cdn"))]
+use crate::ErrorType;
+use base64::{engine::general_purpose::STANDARD, Engine};
+#[cfg(not(feature = "cdn"))]
+use futures_util::future::{self, Ready};
+#[cfg(feature = "cdn")]
+use hyper::{body, client::HttpConnector, Body, Client, Uri};
+#[cfg(feature = "cdn")]
+use hyper_rustls::{HttpsConnector, HttpsConnectorBuilder};
+#[cfg(feature = "cdn")]
+use std::{
+    error::Error as StdError,
+    fmt::{Display, Formatter, Result as FmtResult},
+    sync::OnceLock,
+};
+use twilight_model::util::ImageHash;
+
+/// Base URL of Discord's CDN.
+#[cfg(feature = "cdn")]
+const CDN_URL: &str = "https://cdn.discordapp.com";
+
+/// Download an image from Discord's CDN, such as `avatars/{id}/{hash}.png`.
+///
+/// A single HTTPS client is shared by all downloads.
+#[cfg(feature = "cdn")]
+pub(crate) async fn fetch(path: &str) -> Result<Vec<u8>, Error> {
+    static CLIENT: OnceLock<Client<HttpsConnector<HttpConnector>>> = OnceLock::new();
+
+    let client = CLIENT.get_or_init(|| {
+        let connector = HttpsConnectorBuilder::new()
+            .with_webpki_roots()
+            .https_only()
+            .enable_http1()
+            .build();
+
+        Client::builder().build::<_, Body>(connector)
+    });
+
+    let uri = format!("{CDN_URL}/{path}")
+        .parse::<Uri>()
+        .map_err(Error::request)?;
+    let response = client.get(uri).await.map_err(Error::request)?;
+
+    if !response.status().is_success() {
+        return Err(Error::request(StatusError(response.status().as_u16())));
+    }
+
+    let image = body::to_bytes(response.into_body())
+        .await
+        .map_err(Error::request)?;
+
+    Ok(image.to_vec())
+}
+
+/// Fail to download an image, since downloading requires the `cdn` feature.
+#[cfg(not(feature = "cdn"))]
+pub(crate) fn fetch(path: &str) -> Ready<Result<Vec<u8>, Error>> {
+    future::ready(Err(Error {
+        kind: ErrorType::ImageUnavailable {
+            path: path.to_owned(),
+        },
+        source: None,
+    }))
+}
+
+/// File extension to download an image from Discord's CDN with.
+///
+/// Animated images are downloaded as GIFs so that they keep their animation,
+/// while other images are downloaded as PNGs.
+pub(crate) const fn extension(hash: ImageHash) -> &'static str {
+    if hash.is_animated() {
+        "gif"
+    } else {
+        "png"
+    }
+}
+
+/// MIME type of an image, detected from its contents, if it is a PNG or GIF.
+pub(crate) fn mime_type(image: &[u8]) -> Option<&'static str> {
+    if image.starts_with(b"\x89PNG\r\n\x1a\n") {
+        Some("image/png")
+    } else if image.starts_with(b"GIF87a") || image.starts_with(b"GIF89a") {
+        Some("image/gif")
+    } else {
+        None
+    }
+}
+
+/// Encode an image as a Data URI.
+pub(crate) fn data_uri(mime: &str, image: &[u8]) -> Vec<u8> {
+    format!("data:{mime};base64,{}", STANDARD.encode(image)).into_bytes()
+}
+
+/// CDN responded with an unsuccessful status code.
+#[cfg(feature = "cdn")]
+#[derive(Debug)]
+struct StatusError(u16);
+
+#[cfg(feature = "cdn")]
+impl Display for StatusError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("CDN responded with status code ")?;
+
+        Display::fmt(&self.0, f)
+    }
+}
+
+#[cfg(feature = "cdn")]
+impl StdError for StatusError {}
+
+#[cfg(test)]
+mod tests {
+    use super::{data_uri, extension, mime_type};
+    use twilight_model::util::{image_hash::ImageHashParseError, ImageHash};
+
+    #[test]
+    fn extensions() -> Result<(), ImageHashParseError> {
+        let animated = ImageHash::parse(b"a_1269e74af4df7417b13759eae50c83dc")?;
+        let still = ImageHash::parse(b"1269e74af4df7417b13759eae50c83dc")?;
+
+        assert_eq!("gif", extension(animated));
+        assert_eq!("png", extension(still));
+
+        Ok(())
+    }
+
+    #[test]
+    fn formats() {
+        assert_eq!(Some("image/png"), mime_type(b"\x89PNG\r\n\x1a\nrest"));
+        assert_eq!(Some("image/gif"), mime_type(b"GIF89arest"));
+        assert_eq!(None, mime_type(b"\xff\xd8\xff\xe0"));
+    }
+
+    #[test]
+    fn data_uris() {
+        assert_eq!(
+            b"data:image/png;base64,".to_vec(),
+            data_uri("image/png", b"")
+        );
+        assert_eq!(
+            b"data:image/gif;base64,Zm9vYmE=".to_vec(),
+            data_uri("image/gif", b"fooba")
+        );
+        assert_eq!(
+            b"data:image/gif;base64,Zm9vYmFy".to_vec(),
+            data_uri("image/gif", b"foobar")
+        );
+    }
+}
@@ -38,6 +38,21 @@
 //! contains methods like `list``ChannelMessageResource::list` or
 //! `ChannelMessageResource::post`.
 //!
+//! Executing a request and deserializing its response can be done in one call
+//! by importing the `ExecModel` and `ExecModels` traits:
+//!
+//! ```rust,no_run
+//! use std::env;
+//! use twilight_http_resource::{ExecModels, Resource};
+//! use twilight_http::Client;
+//! use twilight_model::id::Id;
+//!
+//! # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = Client::new(env::var("DISCORD_TOKEN")?);
+//! let messages = client.channels().messages(Id::new(1)).list().models().await?;
+//! # Ok(()) }
+//! ```
+//!
 //! # Installation
 //!
 //! `twilight-http-resource` is not currently on `crates.io`; you can instead
@@ -47,6 +62,13 @@
 //! [dependencies]
 //! twilight-http-resource = { git = "https://github.com/zeylahellyer/twilight-http-resource" }
 //! ```
+//!
+//! # Features
+//!
+//! The `cdn` feature downloads images from Discord's CDN, allowing webhook
+//! rotations and role clones to carry over avatars and icons on their own. It
+//! uses its own HTTPS client, backed by `rustls` and `webpki-roots`. Without
+//! it, these images must be provided by the caller.
 
 #![deny(
     clippy::all,
@@ -61,19 +83,34 @@
 )]
 #![allow(clippy::module_name_repetitions, clippy::must_use_candidate)]
 
+pub mod bulk;
 pub mod channel;
+pub mod export;
 pub mod guild;
+pub mod timestamp;
 pub mod user;
 pub mod webhook;
 
+mod error;
+mod exec;
 mod gateway;
+mod image;
 mod invite;
+mod paging;
 mod resource;
 mod template;
 mod voice_region;
 
 pub use self::{
-    channel::ChannelResource, gateway::GatewayResource, guild::GuildResource,
-    invite::InviteResource, resource::Resource, template::TemplateResource, user::UserResource,
-    voice_region::VoiceRegionResource, webhook::WebhookResource,
+    channel::ChannelResource,
+    error::{Error, ErrorType},
+    exec::{ExecFuture, ExecModel, ExecModels, ExecStream},
+    gateway::GatewayResource,
+    guild::GuildResource,
+    invite::InviteResource,
+    resource::Resource,
+    template::TemplateResource,
+    user::UserResource,
+    voice_region::VoiceRegionResource,
+    webhook::{WebhookClient, WebhookResource},
 };
@@ -0,0 +1,42 @@
+use crate::{Error, ExecStream};
+use futures_util::stream::{self, TryStreamExt};
+use std::future::Future;
+use twilight_model::id::{marker::UserMarker, Id};
+
+/// Stream every model of a list paged by user ID, retrieving pages of up to
+/// `limit` models as needed.
+///
+/// Each page is requested via `fetch` with the ID of the last model of the
+/// previous page, as returned by `cursor`, or `None` for the first page.
+/// Streaming stops after a page with fewer than `limit` models.
+pub(crate) fn user_pages<'a, T, F, Fut>(
+    limit: u16,
+    fetch: F,
+    cursor: fn(&T) -> Id<UserMarker>,
+) -> ExecStream<'a, T>
+where
+    T: Send + 'a,
+    F: Fn(Option<Id<UserMarker>>) -> Fut + Send + 'a,
+    Fut: Future<Output = Result<Vec<T>, Error>> + Send + 'a,
+{
+    let pages = stream::try_unfold(Some(None), move |after| {
+        let page = after.map(&fetch);
+
+        async move {
+            let Some(page) = page else {
+                return Ok::<_, Error>(None);
+            };
+
+            let models = page.await?;
+            let next = if models.len() < usize::from(limit) {
+                None
+            } else {
+                models.last().map(|model| Some(cursor(model)))
+            };
+
+            Ok(Some((stream::iter(models.into_iter().map(Ok)), next)))
+        }
+    });
+
+    Box::pin(pages.try_flatten())
+}
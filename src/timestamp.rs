@@ -0,0 +1,76 @@
+//! Conversions between points in time and Discord timestamps.
+
+use crate::{Error, ErrorType};
+use std::{
+    convert::TryFrom,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use twilight_model::util::Timestamp;
+
+/// Number of microseconds since the Unix epoch of a point in time.
+pub(crate) fn as_micros(time: SystemTime) -> i64 {
+    let micros = time
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_micros());
+
+    i64::try_from(micros).unwrap_or(i64::MAX)
+}
+
+/// Convert a point in time into a timestamp that can be sent to Discord.
+///
+/// # Examples
+///
+/// Create a timestamp for a week ago:
+///
+/// ```
+/// use std::time::{Duration, SystemTime};
+/// use twilight_http_resource::timestamp;
+///
+/// let week_ago = SystemTime::now() - Duration::from_secs(7 * 24 * 60 * 60);
+/// let timestamp = timestamp::from_system_time(week_ago)?;
+/// # Ok::<(), twilight_http_resource::Error>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns an error of type [`ErrorType::Validation`] if the point in time
+/// can't be represented as a timestamp.
+pub fn from_system_time(time: SystemTime) -> Result<Timestamp, Error> {
+    Timestamp::from_micros(as_micros(time)).map_err(|source| Error {
+        kind: ErrorType::Validation,
+        source: Some(Box::new(source)),
+    })
+}
+
+/// Number of milliseconds between the Unix epoch and the Discord epoch, the
+/// first second of 2015.
+const DISCORD_EPOCH_MILLIS: u64 = 1_420_070_400_000;
+
+/// Lowest snowflake that could have been created at a point in time.
+///
+/// Points in time before the Discord epoch map to 0.
+pub(crate) fn snowflake(time: SystemTime) -> u64 {
+    let millis = time
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_millis());
+
+    u64::try_from(millis)
+        .unwrap_or(u64::MAX)
+        .saturating_sub(DISCORD_EPOCH_MILLIS)
+        << 22
+}
+
+#[cfg(test)]
+mod tests {
+    use super::snowflake;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    #[test]
+    fn snowflakes() {
+        assert_eq!(0, snowflake(UNIX_EPOCH));
+
+        // Example from Discord's documentation.
+        let time = UNIX_EPOCH + Duration::from_millis(1_462_015_105_796);
+        assert_eq!(175_928_847_299_117_063 >> 22 << 22, snowflake(time));
+    }
+}
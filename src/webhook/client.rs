@@ -0,0 +1,102 @@
+use super::{WebhookMessageResource, WebhookUrlParseError};
+use twilight_http::{
+    client::Client,
+    request::channel::webhook::{DeleteWebhook, GetWebhook, UpdateWebhookWithToken},
+};
+use twilight_model::id::{marker::WebhookMarker, Id};
+
+/// Work with webhooks without a bot token.
+///
+/// The underlying HTTP client has no authorization, so only operations that
+/// authenticate with a webhook's token are available.
+///
+/// # Examples
+///
+/// Post a message to a webhook by its URL:
+///
+/// ```rust,no_run
+/// use std::env;
+/// use twilight_http_resource::WebhookClient;
+///
+/// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = WebhookClient::new();
+/// let url = env::var("WEBHOOK_URL")?;
+///
+/// client
+///     .messages_from_url(&url)?
+///     .post()
+///     .content("hello")?
+///     .exec()
+///     .await?;
+/// # Ok(()) }
+/// ```
+#[derive(Debug)]
+pub struct WebhookClient(Client);
+
+impl WebhookClient {
+    /// Create a client to work with webhooks without a bot token.
+    pub fn new() -> Self {
+        Self(Client::builder().build())
+    }
+
+    /// Delete a webhook with its token.
+    pub const fn delete<'a>(&'a self, id: Id<WebhookMarker>, token: &'a str) -> DeleteWebhook<'a> {
+        self.0.delete_webhook(id).token(token)
+    }
+
+    /// Get a webhook with its token.
+    pub const fn get<'a>(&'a self, id: Id<WebhookMarker>, token: &'a str) -> GetWebhook<'a> {
+        self.0.webhook(id).token(token)
+    }
+
+    /// Update a webhook with its token.
+    pub const fn patch<'a>(
+        &'a self,
+        id: Id<WebhookMarker>,
+        token: &'a str,
+    ) -> UpdateWebhookWithToken<'a> {
+        self.0.update_webhook_with_token(id, token)
+    }
+}
+
+/// 1:M webhook relationships.
+impl WebhookClient {
+    /// Work with a webhook's messages.
+    pub const fn messages<'a>(
+        &'a self,
+        id: Id<WebhookMarker>,
+        token: &'a str,
+    ) -> WebhookMessageResource<'a> {
+        WebhookMessageResource::new(&self.0, id, token)
+    }
+
+    /// Work with the messages of a webhook identified by its URL.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the URL could not be parsed. Refer to
+    /// [`WebhookUrl::parse`] for the accepted URLs.
+    ///
+    /// [`WebhookUrl::parse`]: super::WebhookUrl::parse
+    pub fn messages_from_url<'a>(
+        &'a self,
+        url: &'a str,
+    ) -> Result<WebhookMessageResource<'a>, WebhookUrlParseError> {
+        WebhookMessageResource::from_url(&self.0, url)
+    }
+}
+
+impl Default for WebhookClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WebhookClient;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(WebhookClient: Debug, Default, Send, Sync);
+}
@@ -1,35 +1,110 @@
+use super::{WebhookRelay, WebhookUrl, WebhookUrlParseError};
 use twilight_http::{
     client::Client,
-    request::channel::webhook::{DeleteWebhookMessage, ExecuteWebhook, UpdateWebhookMessage},
+    request::channel::webhook::{
+        DeleteWebhookMessage, ExecuteWebhook, GetWebhookMessage, UpdateWebhookMessage,
+    },
 };
 use twilight_model::id::{
-    marker::{MessageMarker, WebhookMarker},
+    marker::{ChannelMarker, MessageMarker, WebhookMarker},
     Id,
 };
 
 /// Work with a webhook's messages.
+///
+/// Messages are in the webhook's channel unless the resource is scoped to a
+/// thread via [`in_thread`].
+///
+/// [`in_thread`]: Self::in_thread
 #[derive(Clone, Debug)]
-pub struct WebhookMessageResource<'a>(&'a Client, Id<WebhookMarker>, &'a str);
+pub struct WebhookMessageResource<'a>(
+    &'a Client,
+    Id<WebhookMarker>,
+    &'a str,
+    Option<Id<ChannelMarker>>,
+);
 
 impl<'a> WebhookMessageResource<'a> {
     /// Create a resource instance to work with a webhook's messages.
     pub const fn new(client: &'a Client, webhook_id: Id<WebhookMarker>, token: &'a str) -> Self {
-        Self(client, webhook_id, token)
+        Self(client, webhook_id, token, None)
+    }
+
+    /// Create a resource instance to work with the messages of a webhook
+    /// identified by its URL.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the URL could not be parsed. Refer to
+    /// [`WebhookUrl::parse`] for the accepted URLs.
+    pub fn from_url(client: &'a Client, url: &'a str) -> Result<Self, WebhookUrlParseError> {
+        let url = WebhookUrl::parse(url)?;
+
+        Ok(Self(client, url.id(), url.token(), None))
+    }
+
+    /// URL of the webhook.
+    pub const fn url(&self) -> WebhookUrl<'a> {
+        WebhookUrl::new(self.1, self.2)
+    }
+
+    /// Scope the resource to a thread in the webhook's channel.
+    ///
+    /// Messages posted, updated, deleted, and retrieved via the resource are
+    /// in the thread.
+    #[must_use = "scoping the resource to a thread has no effect if left unused"]
+    pub const fn in_thread(mut self, thread_id: Id<ChannelMarker>) -> Self {
+        self.3 = Some(thread_id);
+
+        self
     }
 
     /// Delete a webhook message.
-    pub const fn delete(self, message_id: Id<MessageMarker>) -> DeleteWebhookMessage<'a> {
-        self.0.delete_webhook_message(self.1, self.2, message_id)
+    pub fn delete(self, message_id: Id<MessageMarker>) -> DeleteWebhookMessage<'a> {
+        let request = self.0.delete_webhook_message(self.1, self.2, message_id);
+
+        match self.3 {
+            Some(thread_id) => request.thread_id(thread_id),
+            None => request,
+        }
+    }
+
+    /// Get a webhook message.
+    pub fn get(self, message_id: Id<MessageMarker>) -> GetWebhookMessage<'a> {
+        let request = self.0.webhook_message(self.1, self.2, message_id);
+
+        match self.3 {
+            Some(thread_id) => request.thread_id(thread_id),
+            None => request,
+        }
     }
 
     /// Update a webhook message.
-    pub const fn patch(self, message_id: Id<MessageMarker>) -> UpdateWebhookMessage<'a> {
-        self.0.update_webhook_message(self.1, self.2, message_id)
+    pub fn patch(self, message_id: Id<MessageMarker>) -> UpdateWebhookMessage<'a> {
+        let request = self.0.update_webhook_message(self.1, self.2, message_id);
+
+        match self.3 {
+            Some(thread_id) => request.thread_id(thread_id),
+            None => request,
+        }
     }
 
     /// Create a webhook message.
-    pub const fn post(self, id: Id<WebhookMarker>) -> ExecuteWebhook<'a> {
-        self.0.execute_webhook(id, self.2)
+    pub fn post(self) -> ExecuteWebhook<'a> {
+        let request = self.0.execute_webhook(self.1, self.2);
+
+        match self.3 {
+            Some(thread_id) => request.thread_id(thread_id),
+            None => request,
+        }
+    }
+}
+
+/// RPC calls.
+impl<'a> WebhookMessageResource<'a> {
+    /// Relay queued messages to the webhook, coalescing them into embeds.
+    pub const fn relay(self) -> WebhookRelay<'a> {
+        WebhookRelay::new(self)
     }
 }
 
@@ -1,8 +1,16 @@
 //! Work with webhooks and their relationships.
 
+mod client;
 mod message;
+mod relay;
+mod url;
 
-pub use self::message::WebhookMessageResource;
+pub use self::{
+    client::WebhookClient,
+    message::WebhookMessageResource,
+    relay::WebhookRelay,
+    url::{WebhookUrl, WebhookUrlParseError, WebhookUrlParseErrorType},
+};
 
 use twilight_http::{
     client::Client,
@@ -58,6 +66,19 @@ impl<'a> WebhookResource<'a> {
     ) -> WebhookMessageResource<'a> {
         WebhookMessageResource::new(self.0, id, token)
     }
+
+    /// Work with the messages of a webhook identified by its URL.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the URL could not be parsed. Refer to
+    /// [`WebhookUrl::parse`] for the accepted URLs.
+    pub fn messages_from_url(
+        &self,
+        url: &'a str,
+    ) -> Result<WebhookMessageResource<'a>, WebhookUrlParseError> {
+        WebhookMessageResource::from_url(self.0, url)
+    }
 }
 
 #[cfg(test)]
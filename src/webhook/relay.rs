@@ -0,0 +1,285 @@
+use super::WebhookMessageResource;
+use crate::{error, Error};
+use std::{collections::VecDeque, iter};
+use tokio::{sync::mpsc::UnboundedReceiver, time};
+use twilight_model::channel::embed::Embed;
+use twilight_validate::{
+    embed::{DESCRIPTION_LENGTH, EMBED_TOTAL_LENGTH},
+    message::EMBED_COUNT_LIMIT,
+};
+
+/// Number of times a ratelimited send is retried before giving up.
+const RATELIMIT_RETRY_LIMIT: u8 = 5;
+
+/// Queue messages for a webhook, coalescing them into as few webhook messages
+/// as possible.
+///
+/// Queued messages are joined by line breaks into embed descriptions of up to
+/// 4096 characters, and as many embeds as fit within Discord's limits are sent
+/// per webhook message. Messages longer than an embed description are split
+/// into multiple embeds, preferring to split on line breaks.
+///
+/// Sends are queued by the client's ratelimiter, and sends that are still
+/// ratelimited are retried up to five times. Messages stay queued until they
+/// have been sent, so they are never dropped when sending fails.
+///
+/// Created via [`WebhookMessageResource::relay`].
+///
+/// # Examples
+///
+/// Relay log lines sent over a channel until all senders are dropped:
+///
+/// ```rust,no_run
+/// use std::env;
+/// use tokio::sync::mpsc;
+/// use twilight_http_resource::WebhookClient;
+///
+/// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = WebhookClient::new();
+/// let url = env::var("WEBHOOK_URL")?;
+/// let (tx, mut rx) = mpsc::unbounded_channel();
+///
+/// tx.send("service started".to_owned())?;
+/// drop(tx);
+///
+/// let mut relay = client.messages_from_url(&url)?.relay();
+/// relay.run(&mut rx).await?;
+/// # Ok(()) }
+/// ```
+#[derive(Debug)]
+#[must_use = "relays must be given messages and flushed"]
+pub struct WebhookRelay<'a> {
+    queue: VecDeque<String>,
+    resource: WebhookMessageResource<'a>,
+}
+
+impl<'a> WebhookRelay<'a> {
+    pub(super) const fn new(resource: WebhookMessageResource<'a>) -> Self {
+        Self {
+            queue: VecDeque::new(),
+            resource,
+        }
+    }
+
+    /// Whether there are no embeds queued.
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+
+    /// Number of embeds queued.
+    ///
+    /// This may differ from the number of messages pushed, since short
+    /// messages are joined into one embed and overlong messages are split
+    /// into multiple embeds.
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// Queue a message.
+    ///
+    /// The message is appended to the last queued embed if it fits. Empty
+    /// messages are ignored.
+    pub fn push(&mut self, message: impl AsRef<str>) {
+        for chunk in split(message.as_ref(), DESCRIPTION_LENGTH) {
+            append(&mut self.queue, chunk, DESCRIPTION_LENGTH);
+        }
+    }
+
+    /// Send all queued messages, returning the number of webhook messages
+    /// sent.
+    ///
+    /// Messages are only removed from the queue once they have been sent, so
+    /// flushing may be retried after an error.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ErrorType::Request`] if a webhook message
+    /// could not be sent, or was still ratelimited after being retried five
+    /// times.
+    ///
+    /// [`ErrorType::Request`]: crate::ErrorType::Request
+    pub async fn flush(&mut self) -> Result<usize, Error> {
+        let mut sent = 0;
+
+        while !self.queue.is_empty() {
+            let embeds = self.next_embeds();
+            let mut retries = 0;
+
+            loop {
+                let request = self.resource.clone().post().embeds(&embeds)?;
+
+                match request.exec().await {
+                    Ok(_) => break,
+                    Err(source) => match error::retry_after(&source) {
+                        Some(duration) if retries < RATELIMIT_RETRY_LIMIT => {
+                            retries += 1;
+                            time::sleep(duration).await;
+                        }
+                        _ => return Err(Error::from(source)),
+                    },
+                }
+            }
+
+            self.queue.drain(..embeds.len());
+            sent += 1;
+        }
+
+        Ok(sent)
+    }
+
+    /// Relay messages received from a channel until all of its senders are
+    /// dropped.
+    ///
+    /// Messages received while a webhook message is being sent are coalesced
+    /// into the next webhook message.
+    ///
+    /// If sending fails then the unsent messages remain queued on the relay
+    /// and the rest remain in the receiver, so running the relay again
+    /// resumes where it stopped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ErrorType::Request`] if a webhook message
+    /// could not be sent, or was still ratelimited after being retried five
+    /// times.
+    ///
+    /// [`ErrorType::Request`]: crate::ErrorType::Request
+    pub async fn run(&mut self, receiver: &mut UnboundedReceiver<String>) -> Result<(), Error> {
+        self.flush().await?;
+
+        while let Some(message) = receiver.recv().await {
+            self.push(message);
+
+            while let Ok(message) = receiver.try_recv() {
+                self.push(message);
+            }
+
+            self.flush().await?;
+        }
+
+        Ok(())
+    }
+
+    /// Embeds for the queued messages that fit into the next webhook message.
+    fn next_embeds(&self) -> Vec<Embed> {
+        let mut embeds = Vec::new();
+        let mut total = 0;
+
+        for description in &self.queue {
+            let length = description.chars().count();
+
+            if embeds.len() == EMBED_COUNT_LIMIT || total + length > EMBED_TOTAL_LENGTH {
+                break;
+            }
+
+            total += length;
+            embeds.push(embed(description.clone()));
+        }
+
+        embeds
+    }
+}
+
+/// Append a line to the last description in a queue if the result is at
+/// most `limit` characters, otherwise queue it as a new description.
+fn append(queue: &mut VecDeque<String>, line: &str, limit: usize) {
+    if let Some(last) = queue.back_mut() {
+        let separator = usize::from(!last.ends_with('\n'));
+
+        if last.chars().count() + separator + line.chars().count() <= limit {
+            if separator == 1 {
+                last.push('\n');
+            }
+
+            last.push_str(line);
+
+            return;
+        }
+    }
+
+    queue.push_back(line.to_owned());
+}
+
+/// Create an embed with only a description.
+fn embed(description: String) -> Embed {
+    Embed {
+        author: None,
+        color: None,
+        description: Some(description),
+        fields: Vec::new(),
+        footer: None,
+        image: None,
+        kind: "rich".to_owned(),
+        provider: None,
+        thumbnail: None,
+        timestamp: None,
+        title: None,
+        url: None,
+        video: None,
+    }
+}
+
+/// Split a message into chunks of at most `limit` characters, preferring to
+/// split after a line break.
+fn split(mut message: &str, limit: usize) -> impl Iterator<Item = &str> {
+    iter::from_fn(move || {
+        if message.is_empty() {
+            return None;
+        }
+
+        let end = message
+            .char_indices()
+            .nth(limit)
+            .map_or(message.len(), |(index, _)| index);
+
+        let end = if end == message.len() {
+            end
+        } else {
+            message[..end]
+                .rfind('\n')
+                .filter(|&index| index > 0)
+                .map_or(end, |index| index + 1)
+        };
+
+        let (chunk, rest) = message.split_at(end);
+        message = rest;
+
+        Some(chunk)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{append, split, WebhookRelay};
+    use static_assertions::assert_impl_all;
+    use std::{collections::VecDeque, fmt::Debug};
+
+    assert_impl_all!(WebhookRelay<'_>: Debug, Send, Sync);
+
+    #[test]
+    fn split_chunks() {
+        assert_eq!(vec!["abc", "de"], split("abcde", 3).collect::<Vec<_>>());
+        assert_eq!(
+            vec!["a\n", "bcd", "e"],
+            split("a\nbcde", 3).collect::<Vec<_>>()
+        );
+        assert_eq!(vec!["éé", "é"], split("ééé", 2).collect::<Vec<_>>());
+        assert!(split("", 3).next().is_none());
+    }
+
+    #[test]
+    fn append_lines() {
+        let mut queue = VecDeque::new();
+
+        append(&mut queue, "ab", 5);
+        append(&mut queue, "cd", 5);
+        assert_eq!(vec!["ab\ncd"], Vec::from(queue.clone()));
+
+        append(&mut queue, "e", 5);
+        assert_eq!(vec!["ab\ncd", "e"], Vec::from(queue.clone()));
+
+        append(&mut queue, "f\n", 5);
+        append(&mut queue, "gh", 5);
+        assert_eq!(vec!["ab\ncd", "e\nf\n", "gh"], Vec::from(queue));
+    }
+}
@@ -0,0 +1,299 @@
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::id::{marker::WebhookMarker, Id};
+
+/// Hosts that webhook URLs may be on.
+const HOSTS: &[&str] = &[
+    "discord.com",
+    "canary.discord.com",
+    "ptb.discord.com",
+    "discordapp.com",
+    "canary.discordapp.com",
+    "ptb.discordapp.com",
+];
+
+/// ID and token of a webhook, parsed from or rendered as a webhook URL.
+///
+/// # Examples
+///
+/// Parse a webhook URL and render it back out:
+///
+/// ```
+/// use twilight_http_resource::webhook::WebhookUrl;
+///
+/// let url = WebhookUrl::parse("https://canary.discord.com/api/webhooks/123/abc")?;
+/// assert_eq!(123, url.id().get());
+/// assert_eq!("abc", url.token());
+/// assert_eq!("https://discord.com/api/webhooks/123/abc", url.to_string());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct WebhookUrl<'a> {
+    id: Id<WebhookMarker>,
+    token: &'a str,
+}
+
+impl<'a> WebhookUrl<'a> {
+    /// Create a webhook URL from a webhook's ID and token.
+    pub const fn new(id: Id<WebhookMarker>, token: &'a str) -> Self {
+        Self { id, token }
+    }
+
+    /// Parse a webhook URL such as
+    /// `https://discord.com/api/webhooks/{id}/{token}`.
+    ///
+    /// The `canary` and `ptb` subdomains are accepted, as is the legacy
+    /// `discordapp.com` host and a versioned API path such as `/api/v10`.
+    /// Query strings and fragments are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`UnsupportedScheme`] if the URL does not use
+    /// HTTPS.
+    ///
+    /// Returns an error of type [`UnsupportedHost`] if the URL's host is not
+    /// a Discord host.
+    ///
+    /// Returns an error of type [`InvalidPath`] if the URL's path is not a
+    /// webhook path.
+    ///
+    /// Returns an error of type [`InvalidId`] if the webhook ID is not a
+    /// valid ID.
+    ///
+    /// Returns an error of type [`InvalidToken`] if the webhook token is empty
+    /// or contains invalid characters.
+    ///
+    /// [`InvalidId`]: WebhookUrlParseErrorType::InvalidId
+    /// [`InvalidPath`]: WebhookUrlParseErrorType::InvalidPath
+    /// [`InvalidToken`]: WebhookUrlParseErrorType::InvalidToken
+    /// [`UnsupportedHost`]: WebhookUrlParseErrorType::UnsupportedHost
+    /// [`UnsupportedScheme`]: WebhookUrlParseErrorType::UnsupportedScheme
+    pub fn parse(url: &'a str) -> Result<Self, WebhookUrlParseError> {
+        let rest = url.strip_prefix("https://").ok_or(WebhookUrlParseError {
+            kind: WebhookUrlParseErrorType::UnsupportedScheme,
+            source: None,
+        })?;
+
+        let rest = rest.split(&['?', '#'][..]).next().unwrap_or_default();
+        let (host, path) = rest.split_once('/').unwrap_or((rest, ""));
+
+        if !HOSTS.contains(&host) {
+            return Err(WebhookUrlParseError {
+                kind: WebhookUrlParseErrorType::UnsupportedHost,
+                source: None,
+            });
+        }
+
+        let mut segments = path.trim_end_matches('/').split('/');
+
+        if segments.next() != Some("api") {
+            return Err(WebhookUrlParseError::invalid_path());
+        }
+
+        let mut segment = segments.next();
+
+        if segment.is_some_and(is_api_version) {
+            segment = segments.next();
+        }
+
+        if segment != Some("webhooks") {
+            return Err(WebhookUrlParseError::invalid_path());
+        }
+
+        let (Some(id), Some(token), None) = (segments.next(), segments.next(), segments.next())
+        else {
+            return Err(WebhookUrlParseError::invalid_path());
+        };
+
+        let id = id
+            .parse::<Id<WebhookMarker>>()
+            .map_err(|source| WebhookUrlParseError {
+                kind: WebhookUrlParseErrorType::InvalidId,
+                source: Some(Box::new(source)),
+            })?;
+
+        if token.is_empty()
+            || !token
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        {
+            return Err(WebhookUrlParseError {
+                kind: WebhookUrlParseErrorType::InvalidToken,
+                source: None,
+            });
+        }
+
+        Ok(Self { id, token })
+    }
+
+    /// ID of the webhook.
+    pub const fn id(&self) -> Id<WebhookMarker> {
+        self.id
+    }
+
+    /// Token of the webhook.
+    pub const fn token(&self) -> &'a str {
+        self.token
+    }
+}
+
+impl Display for WebhookUrl<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("https://discord.com/api/webhooks/")?;
+        Display::fmt(&self.id, f)?;
+        f.write_str("/")?;
+
+        f.write_str(self.token)
+    }
+}
+
+/// Whether a path segment is an API version, such as `v10`.
+fn is_api_version(segment: &str) -> bool {
+    segment
+        .strip_prefix('v')
+        .is_some_and(|version| !version.is_empty() && version.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// Parsing a webhook URL failed.
+#[derive(Debug)]
+pub struct WebhookUrlParseError {
+    kind: WebhookUrlParseErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl WebhookUrlParseError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &WebhookUrlParseErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        WebhookUrlParseErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, self.source)
+    }
+
+    const fn invalid_path() -> Self {
+        Self {
+            kind: WebhookUrlParseErrorType::InvalidPath,
+            source: None,
+        }
+    }
+}
+
+impl Display for WebhookUrlParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.kind {
+            WebhookUrlParseErrorType::InvalidId => f.write_str("webhook ID is invalid"),
+            WebhookUrlParseErrorType::InvalidPath => {
+                f.write_str("path is not of the form /api/webhooks/{id}/{token}")
+            }
+            WebhookUrlParseErrorType::InvalidToken => f.write_str("webhook token is invalid"),
+            WebhookUrlParseErrorType::UnsupportedHost => f.write_str("host is not a Discord host"),
+            WebhookUrlParseErrorType::UnsupportedScheme => f.write_str("scheme is not https"),
+        }
+    }
+}
+
+impl Error for WebhookUrlParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`WebhookUrlParseError`] that occurred.
+#[derive(Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum WebhookUrlParseErrorType {
+    /// Webhook ID is not a valid ID.
+    InvalidId,
+    /// Path is not of the form `/api/webhooks/{id}/{token}`.
+    InvalidPath,
+    /// Webhook token is empty or contains invalid characters.
+    InvalidToken,
+    /// Host is not a Discord host.
+    UnsupportedHost,
+    /// Scheme is not HTTPS.
+    UnsupportedScheme,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{WebhookUrl, WebhookUrlParseError, WebhookUrlParseErrorType};
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug, hash::Hash};
+    use twilight_model::id::Id;
+
+    assert_impl_all!(WebhookUrl<'_>: Clone, Copy, Debug, Eq, Hash, PartialEq, Send, Sync);
+    assert_impl_all!(WebhookUrlParseError: Debug, Error, Send, Sync);
+    assert_impl_all!(WebhookUrlParseErrorType: Debug, Eq, PartialEq, Send, Sync);
+
+    #[test]
+    fn parse() -> Result<(), WebhookUrlParseError> {
+        let expected = WebhookUrl::new(Id::new(123), "a-b_C");
+
+        for url in [
+            "https://discord.com/api/webhooks/123/a-b_C",
+            "https://canary.discord.com/api/webhooks/123/a-b_C",
+            "https://ptb.discord.com/api/v10/webhooks/123/a-b_C/",
+            "https://discordapp.com/api/webhooks/123/a-b_C?wait=true",
+        ] {
+            assert_eq!(expected, WebhookUrl::parse(url)?);
+        }
+
+        assert_eq!(
+            "https://discord.com/api/webhooks/123/a-b_C",
+            expected.to_string()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_errors() {
+        for (url, kind) in [
+            (
+                "http://discord.com/api/webhooks/123/abc",
+                WebhookUrlParseErrorType::UnsupportedScheme,
+            ),
+            (
+                "https://example.com/api/webhooks/123/abc",
+                WebhookUrlParseErrorType::UnsupportedHost,
+            ),
+            (
+                "https://discord.com/api/channels/123/abc",
+                WebhookUrlParseErrorType::InvalidPath,
+            ),
+            (
+                "https://discord.com/api/webhooks/123",
+                WebhookUrlParseErrorType::InvalidPath,
+            ),
+            (
+                "https://discord.com/api/webhooks/0/abc",
+                WebhookUrlParseErrorType::InvalidId,
+            ),
+            (
+                "https://discord.com/api/webhooks/123/a%20b",
+                WebhookUrlParseErrorType::InvalidToken,
+            ),
+        ] {
+            assert_eq!(&kind, WebhookUrl::parse(url).unwrap_err().kind());
+        }
+    }
+}